@@ -0,0 +1,64 @@
+//! Compares serial and parallel decoding of a large interleaved vertex buffer.
+//!
+//! Run with `cargo bench --features rayon --bench read_par`.
+
+use std::time::{Duration, Instant};
+
+const VERTEX_COUNT: usize = 4_000_000;
+const STRIDE: usize = 24;
+const ITERATIONS: u32 = 10;
+
+fn document(byte_length: usize) -> gltf::Document {
+    let json = serde_json::json!({
+        "asset": { "version": "2.0" },
+        "buffers": [{ "byteLength": byte_length }],
+        "bufferViews": [{ "buffer": 0, "byteLength": byte_length, "byteStride": STRIDE }],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": VERTEX_COUNT, "type": "VEC3" },
+            {
+                "bufferView": 0,
+                "byteOffset": 12,
+                "componentType": 5126,
+                "count": VERTEX_COUNT,
+                "type": "VEC3",
+            },
+        ],
+        "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0, "NORMAL": 1 } }] }],
+    });
+    let root = gltf::json::deserialize::from_value(json).unwrap();
+    gltf::Document::from_json_without_validation(root)
+}
+
+fn time<T>(name: &str, mut f: impl FnMut() -> T) {
+    let mut total = Duration::default();
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        std::hint::black_box(f());
+        total += start.elapsed();
+    }
+    println!("{:<20} {:>10.2?} per iteration", name, total / ITERATIONS);
+}
+
+fn main() {
+    let bin: Vec<u8> = (0..VERTEX_COUNT * STRIDE / 4)
+        .flat_map(|i| (i as f32).to_le_bytes())
+        .collect();
+    let document = document(bin.len());
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let reader = primitive.reader(|_| Some(&bin));
+
+    time("read_positions", || {
+        reader.read_positions().unwrap().collect::<Vec<_>>()
+    });
+    time("read_positions_par", || {
+        reader.read_positions_par().unwrap()
+    });
+    time("to_soa", || reader.to_soa());
+    time("to_soa_par", || reader.to_soa_par());
+}
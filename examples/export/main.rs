@@ -138,6 +138,7 @@ fn export(output: Output) {
         indices: None,
         material: None,
         mode: Valid(json::mesh::Mode::Triangles),
+        extension_mode: Default::default(),
         targets: None,
     };
 
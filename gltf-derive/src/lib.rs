@@ -9,7 +9,7 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 use syn::DeriveInput;
 
-#[proc_macro_derive(Validate)]
+#[proc_macro_derive(Validate, attributes(gltf))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     expand(&syn::parse_macro_input!(input as DeriveInput)).into()
 }
@@ -23,6 +23,9 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
         _ => panic!("#[derive(Validate)] only works on `struct`s"),
     };
     let ident = &ast.ident;
+    let hook = validate_hook(ast)
+        .map(|hook| quote!(#hook(self, _root, _path, _report)))
+        .unwrap_or_default();
     let validations: Vec<TokenStream> = fields
         .iter()
         .map(|f| f.ident.as_ref().unwrap())
@@ -55,7 +58,35 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
                 #(
                     #validations;
                 )*
+                #hook
             }
         }
     )
 }
+
+/// Parses the `#[gltf(validate_hook = "path")]` attribute, naming a function
+/// with the signature of `Validate::validate` that is called after the fields
+/// have been validated.
+fn validate_hook(ast: &DeriveInput) -> Option<syn::Path> {
+    use syn::{Lit, Meta, NestedMeta};
+
+    let mut hook = None;
+    for attr in ast.attrs.iter().filter(|attr| attr.path.is_ident("gltf")) {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list,
+            _ => panic!("expected `#[gltf(...)]`"),
+        };
+        for nested in list.nested {
+            if let NestedMeta::Meta(Meta::NameValue(name_value)) = nested {
+                if let (true, Lit::Str(lit)) =
+                    (name_value.path.is_ident("validate_hook"), name_value.lit)
+                {
+                    hook = Some(lit.parse().expect("expected a function path"));
+                    continue;
+                }
+            }
+            panic!("expected `#[gltf(validate_hook = \"...\")]`");
+        }
+    }
+    hook
+}
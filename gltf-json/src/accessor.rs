@@ -101,7 +101,11 @@ pub mod sparse {
         pub component_type: Checked<IndexComponentType>,
 
         /// Extension specific data.
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(
+            default,
+            deserialize_with = "crate::extensions::recorded",
+            skip_serializing_if = "Option::is_none"
+        )]
         pub extensions: Option<extensions::accessor::sparse::Indices>,
 
         /// Optional application specific data.
@@ -131,7 +135,11 @@ pub mod sparse {
         pub values: Values,
 
         /// Extension specific data.
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(
+            default,
+            deserialize_with = "crate::extensions::recorded",
+            skip_serializing_if = "Option::is_none"
+        )]
         pub extensions: Option<extensions::accessor::sparse::Sparse>,
 
         /// Optional application specific data.
@@ -157,7 +165,11 @@ pub mod sparse {
         pub byte_offset: u32,
 
         /// Extension specific data.
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(
+            default,
+            deserialize_with = "crate::extensions::recorded",
+            skip_serializing_if = "Option::is_none"
+        )]
         pub extensions: Option<extensions::accessor::sparse::Values>,
 
         /// Optional application specific data.
@@ -191,7 +203,11 @@ pub struct Accessor {
     pub component_type: Checked<GenericComponentType>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::accessor::Accessor>,
 
     /// Optional application specific data.
@@ -228,6 +244,26 @@ pub struct Accessor {
     pub sparse: Option<sparse::Sparse>,
 }
 
+impl Accessor {
+    /// Returns the size of a single element in bytes, i.e. the component
+    /// byte size multiplied by the number of components.
+    ///
+    /// Each column of a matrix is aligned to a 4-byte boundary, so matrices of
+    /// 1-byte and 2-byte components may include padding.
+    ///
+    /// Returns `None` if either the component type or the type is invalid.
+    pub fn element_byte_size(&self) -> Option<usize> {
+        let component_size = self.component_type.valid()?.0.size();
+        let columns = match self.type_.valid()? {
+            Type::Mat2 => 2,
+            Type::Mat3 => 3,
+            Type::Mat4 => 4,
+            type_ => return Some(component_size * type_.multiplicity()),
+        };
+        Some(columns * ((columns * component_size + 3) & !3))
+    }
+}
+
 impl Validate for Accessor {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
     where
@@ -258,6 +294,33 @@ impl Validate for Accessor {
             .validate(root, || path().field("normalized"), report);
         self.sparse
             .validate(root, || path().field("sparse"), report);
+
+        // spec: buffer views of sparse indices and values **must not** have
+        // `byteStride` defined.
+        if let Some(ref sparse) = self.sparse {
+            let views = [
+                ("indices", sparse.indices.buffer_view),
+                ("values", sparse.values.buffer_view),
+            ];
+            for &(field, view) in views.iter() {
+                if root.get(view).and_then(|view| view.byte_stride).is_some() {
+                    report(
+                        &|| path().field("sparse").field(field).field("bufferView"),
+                        Error::Invalid,
+                    );
+                }
+            }
+        }
+
+        // spec: `normalized` **must not** be `true` for `FLOAT` or
+        // `UNSIGNED_INT` components.
+        if self.normalized {
+            if let Checked::Valid(GenericComponentType(ComponentType::F32 | ComponentType::U32)) =
+                self.component_type
+            {
+                report(&|| path().field("normalized"), Error::Invalid);
+            }
+        }
     }
 }
 
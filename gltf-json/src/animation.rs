@@ -56,7 +56,11 @@ pub enum Property {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Animation {
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::animation::Animation>,
 
     /// Optional application specific data.
@@ -94,7 +98,11 @@ pub struct Channel {
     pub target: Target,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::animation::Channel>,
 
     /// Optional application specific data.
@@ -108,7 +116,11 @@ pub struct Channel {
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Target {
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::animation::Target>,
 
     /// Optional application specific data.
@@ -129,7 +141,11 @@ pub struct Target {
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Sampler {
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::animation::Sampler>,
 
     /// Optional application specific data.
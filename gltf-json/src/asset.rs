@@ -10,7 +10,11 @@ pub struct Asset {
     pub copyright: Option<String>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::asset::Asset>,
 
     /// Optional application specific data.
@@ -60,7 +60,11 @@ pub struct Buffer {
     pub uri: Option<String>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::buffer::Buffer>,
 
     /// Optional application specific data.
@@ -108,7 +112,11 @@ pub struct View {
     pub target: Option<Checked<Target>>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::buffer::View>,
 
     /// Optional application specific data.
@@ -44,7 +44,11 @@ pub struct Camera {
     pub type_: Checked<Type>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::camera::Camera>,
 
     /// Optional application specific data.
@@ -70,7 +74,11 @@ pub struct Orthographic {
     pub znear: f32,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::camera::Orthographic>,
 
     /// Optional application specific data.
@@ -99,7 +107,11 @@ pub struct Perspective {
     pub znear: f32,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::camera::Perspective>,
 
     /// Optional application specific data.
@@ -1,3 +1,5 @@
+#[cfg(feature = "EXT_accessor_double")]
+use crate::{buffer, Index};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
@@ -21,4 +23,30 @@ pub mod sparse {
 
 /// A typed view into a buffer view.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Accessor {}
+pub struct Accessor {
+    #[cfg(feature = "EXT_accessor_double")]
+    #[serde(
+        default,
+        rename = "EXT_accessor_double",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_accessor_double: Option<ExtAccessorDouble>,
+}
+
+/// A double precision copy of the accessor data, as defined by the custom
+/// `EXT_accessor_double` extension.
+///
+/// The copy holds `count` elements of the accessor's type with `f64`
+/// components, while the accessor itself keeps single precision data for
+/// clients without support for the extension.
+#[cfg(feature = "EXT_accessor_double")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct ExtAccessorDouble {
+    /// The buffer view holding the `f64` components.
+    #[serde(rename = "bufferView")]
+    pub buffer_view: Index<buffer::View>,
+
+    /// The offset relative to the start of the buffer view in bytes.
+    #[serde(default, rename = "byteOffset")]
+    pub byte_offset: u32,
+}
@@ -36,6 +36,140 @@ pub mod texture;
 
 pub use self::root::Root;
 
+use serde::de;
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+
+thread_local! {
+    /// What is found by [`recorded`] and [`record_target_attributes`] while
+    /// [`record`] is active.
+    static RECORDED: RefCell<Option<Recorded>> = const { RefCell::new(None) };
+}
+
+/// The data collected by [`record`].
+#[derive(Default)]
+pub(crate) struct Recorded {
+    /// The sorted names of the extensions found by [`recorded`].
+    pub extensions: Vec<String>,
+
+    /// The attributes other than `POSITION`, `NORMAL` and `TANGENT` of each
+    /// morph target, in the order the morph targets were deserialized.
+    pub target_attributes: Vec<Vec<String>>,
+}
+
+/// Runs `f`, returning its result and what was recorded in the meantime.
+pub(crate) fn record<T>(f: impl FnOnce() -> T) -> (T, Recorded) {
+    let outer = RECORDED.with(|recorded| recorded.replace(Some(Recorded::default())));
+    let result = f();
+    let mut recorded = RECORDED
+        .with(|recorded| recorded.replace(outer))
+        .unwrap_or_default();
+    recorded.extensions.sort();
+    recorded.extensions.dedup();
+    (result, recorded)
+}
+
+/// Records the unexpected attributes of a morph target while [`record`] is
+/// active.
+pub(crate) fn record_target_attributes(names: Vec<String>) {
+    RECORDED.with(|recorded| {
+        if let Some(recorded) = recorded.borrow_mut().as_mut() {
+            recorded.target_attributes.push(names);
+        }
+    });
+}
+
+/// Deserializes the `extensions` object of a glTF object, recording the name
+/// of every extension present, including those that the enabled features do
+/// not retain.
+pub(crate) fn recorded<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: de::Deserializer<'de>,
+    T: de::Deserialize<'de>,
+{
+    struct Visitor<T>(PhantomData<T>);
+
+    impl<'de, T: de::Deserialize<'de>> de::Visitor<'de> for Visitor<T> {
+        type Value = Option<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "an extensions object")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_map(self)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let map = de::value::MapAccessDeserializer::new(RecordingMap(map));
+            T::deserialize(map).map(Some)
+        }
+    }
+
+    /// Forwards to a map, recording each key.
+    struct RecordingMap<A>(A);
+
+    impl<'de, A: de::MapAccess<'de>> de::MapAccess<'de> for RecordingMap<A> {
+        type Error = A::Error;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+        where
+            K: de::DeserializeSeed<'de>,
+        {
+            self.0.next_key_seed(KeySeed(seed))
+        }
+
+        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+        where
+            V: de::DeserializeSeed<'de>,
+        {
+            self.0.next_value_seed(seed)
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            self.0.size_hint()
+        }
+    }
+
+    /// Records a key before passing it on to the seed of the object.
+    struct KeySeed<K>(K);
+
+    impl<'de, K: de::DeserializeSeed<'de>> de::DeserializeSeed<'de> for KeySeed<K> {
+        type Value = K::Value;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            let key: String = de::Deserialize::deserialize(deserializer)?;
+            RECORDED.with(|recorded| {
+                if let Some(recorded) = recorded.borrow_mut().as_mut() {
+                    recorded.extensions.push(key.clone());
+                }
+            });
+            self.0
+                .deserialize(de::IntoDeserializer::<D::Error>::into_deserializer(key))
+        }
+    }
+
+    deserializer.deserialize_option(Visitor(PhantomData))
+}
+
 /// Names of glTF 2.0 extensions enabled by the user.
 pub const ENABLED_EXTENSIONS: &[&str] = &[
     #[cfg(feature = "KHR_lights_punctual")]
@@ -50,6 +184,8 @@ pub const ENABLED_EXTENSIONS: &[&str] = &[
     "KHR_materials_transmission",
     #[cfg(feature = "KHR_materials_ior")]
     "KHR_materials_ior",
+    #[cfg(feature = "EXT_accessor_double")]
+    "EXT_accessor_double",
 ];
 
 /// Names of glTF 2.0 extensions supported by the library.
@@ -60,4 +196,5 @@ pub const SUPPORTED_EXTENSIONS: &[&str] = &[
     "KHR_texture_transform",
     "KHR_materials_transmission",
     "KHR_materials_ior",
+    "EXT_accessor_double",
 ];
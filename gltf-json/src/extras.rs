@@ -13,6 +13,59 @@ pub type Extras = Option<::std::boxed::Box<RawValue>>;
 #[cfg(not(feature = "extras"))]
 pub type Extras = Void;
 
+/// How to combine the `extras` of two objects that are merged into one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExtrasMerge {
+    /// Keep the extras of the first object, falling back to those of the
+    /// second if the first has none.
+    First,
+
+    /// Keep the extras of the second object, falling back to those of the
+    /// first if the second has none.
+    Second,
+
+    /// Combine the members of both when both are JSON objects, preferring
+    /// the first object's value for duplicate keys. Otherwise behaves like
+    /// `First`.
+    Union,
+}
+
+/// Combines the extras of two objects according to `policy`.
+#[cfg(feature = "extras")]
+pub fn merge(first: Extras, second: Extras, policy: ExtrasMerge) -> Extras {
+    use serde_json::{Map, Value};
+
+    let (first, second) = match (first, second) {
+        (Some(first), Some(second)) => (first, second),
+        (first, second) => return first.or(second),
+    };
+    match policy {
+        ExtrasMerge::First => Some(first),
+        ExtrasMerge::Second => Some(second),
+        ExtrasMerge::Union => {
+            let objects = (
+                serde_json::from_str::<Map<String, Value>>(first.get()),
+                serde_json::from_str::<Map<String, Value>>(second.get()),
+            );
+            match objects {
+                (Ok(mut union), Ok(other)) => {
+                    for (key, value) in other {
+                        union.entry(key).or_insert(value);
+                    }
+                    serde_json::value::to_raw_value(&union).ok().or(Some(first))
+                }
+                _ => Some(first),
+            }
+        }
+    }
+}
+
+/// Combines the extras of two objects according to `policy`.
+#[cfg(not(feature = "extras"))]
+pub fn merge(_first: Extras, _second: Extras, _policy: ExtrasMerge) -> Extras {
+    Void::default()
+}
+
 /// Type representing no user-defined data.
 #[derive(Clone, Default, Serialize, Deserialize, Validate)]
 pub struct Void {
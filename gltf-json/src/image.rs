@@ -32,7 +32,11 @@ pub struct Image {
     pub uri: Option<String>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::image::Image>,
 
     /// Optional application specific data.
@@ -0,0 +1,76 @@
+use crate::Index;
+use serde::de;
+use serde_derive::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Deserializes either an array or a single value in its place.
+///
+/// The value is deserialized directly from the input rather than buffered,
+/// so that types such as `RawValue` extras are supported.
+pub fn one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: de::Deserializer<'de>,
+    T: de::Deserialize<'de>,
+{
+    struct Visitor<T>(PhantomData<T>);
+
+    impl<'de, T: de::Deserialize<'de>> de::Visitor<'de> for Visitor<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "an array or a single object")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(value) = seq.next_element()? {
+                values.push(value);
+            }
+            Ok(values)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let value = de::Deserialize::deserialize(de::value::MapAccessDeserializer::new(map))?;
+            Ok(vec![value])
+        }
+    }
+
+    deserializer.deserialize_any(Visitor(PhantomData))
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString {
+    Number(u32),
+    String(String),
+}
+
+/// Deserializes a map of indices, accepting stringified integers such as
+/// `"0"` in place of numbers.
+pub fn index_map<'de, D, K, T>(deserializer: D) -> Result<HashMap<K, Index<T>>, D::Error>
+where
+    D: de::Deserializer<'de>,
+    K: de::Deserialize<'de> + Eq + Hash,
+{
+    let map: HashMap<K, NumberOrString> = de::Deserialize::deserialize(deserializer)?;
+    map.into_iter()
+        .map(|(key, value)| {
+            let index = match value {
+                NumberOrString::Number(index) => index,
+                NumberOrString::String(string) => string.trim().parse().map_err(|_| {
+                    de::Error::invalid_value(de::Unexpected::Str(&string), &"an index")
+                })?,
+            };
+            Ok((key, Index::new(index)))
+        })
+        .collect()
+}
@@ -23,6 +23,10 @@ pub mod extras;
 /// Contains `Image` and other related data structures.
 pub mod image;
 
+/// Contains deserializers that tolerate common authoring mistakes.
+#[cfg(feature = "lenient")]
+mod lenient;
+
 /// Contains `Material` and other related data structures.
 pub mod material;
 
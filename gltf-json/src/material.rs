@@ -119,7 +119,11 @@ pub struct Material {
     pub emissive_factor: EmissiveFactor,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::material::Material>,
 
     /// Optional application specific data.
@@ -166,7 +170,11 @@ pub struct PbrMetallicRoughness {
     pub metallic_roughness_texture: Option<texture::Info>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::material::PbrMetallicRoughness>,
 
     /// Optional application specific data.
@@ -192,7 +200,11 @@ pub struct NormalTexture {
     pub tex_coord: u32,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::material::NormalTexture>,
 
     /// Optional application specific data.
@@ -221,7 +233,11 @@ pub struct OcclusionTexture {
     pub tex_coord: u32,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::material::OcclusionTexture>,
 
     /// Optional application specific data.
@@ -1,10 +1,11 @@
-use crate::validation::{Checked, Error, Validate};
-use crate::{accessor, extensions, material, Extras, Index};
+use crate::validation::{Checked, Error, Profile, Validate};
+use crate::{accessor, buffer, extensions, material, Extras, Index};
 use gltf_derive::Validate;
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
 use serde_json::from_value;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::fmt;
 
 /// Corresponds to `GL_POINTS`.
@@ -39,6 +40,13 @@ pub const VALID_MODES: &[u32] = &[
     TRIANGLE_FAN,
 ];
 
+/// Returns the `Mode` at position `index` of `VALID_MODES`.
+///
+/// See [`Mode::valid_index`] for the reverse mapping.
+pub fn mode_from_valid_index(index: usize) -> Option<Mode> {
+    VALID_MODES.get(index).copied().and_then(Mode::from_gl_enum)
+}
+
 /// All valid semantic names for Morph targets.
 pub const VALID_MORPH_TARGETS: &[&str] = &["POSITION", "NORMAL", "TANGENT"];
 
@@ -67,6 +75,41 @@ pub enum Mode {
     TriangleFan,
 }
 
+/// Storage for a raw `mode` value outside of the specification, such as one
+/// added by a vendor extension.
+///
+/// The value is only kept with the `extension_modes` feature and is read with
+/// `Primitive::extension_mode`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ExtensionMode(Option<u64>);
+
+impl ExtensionMode {
+    fn is_none(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<'de> de::Deserialize<'de> for ExtensionMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        <u64 as de::Deserialize>::deserialize(deserializer).map(|raw| ExtensionMode(Some(raw)))
+    }
+}
+
+impl ser::Serialize for ExtensionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match self.0 {
+            Some(raw) => serializer.serialize_u64(raw),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
 /// A set of primitives to be rendered.
 ///
 /// A node can contain one or more meshes and its transform places the meshes in
@@ -74,7 +117,11 @@ pub enum Mode {
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Mesh {
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::mesh::Mesh>,
 
     /// Optional application specific data.
@@ -89,6 +136,13 @@ pub struct Mesh {
     pub name: Option<String>,
 
     /// Defines the geometry to be renderered with a material.
+    ///
+    /// With the `lenient` feature, a single primitive object is also accepted
+    /// in place of an array.
+    #[cfg_attr(
+        feature = "lenient",
+        serde(deserialize_with = "crate::lenient::one_or_many")
+    )]
     pub primitives: Vec<Primitive>,
 
     /// Defines the weights to be applied to the morph targets.
@@ -98,13 +152,22 @@ pub struct Mesh {
 
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Primitive {
     /// Maps attribute semantic names to the `Accessor`s containing the
     /// corresponding attribute data.
+    #[cfg_attr(
+        feature = "lenient",
+        serde(deserialize_with = "crate::lenient::index_map")
+    )]
     pub attributes: HashMap<Checked<Semantic>, Index<accessor::Accessor>>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::mesh::Primitive>,
 
     /// Optional application specific data.
@@ -122,9 +185,24 @@ pub struct Primitive {
     pub material: Option<Index<material::Material>>,
 
     /// The type of primitives to render.
-    #[serde(default, skip_serializing_if = "is_primitive_mode_default")]
+    #[serde(
+        default,
+        skip_deserializing,
+        skip_serializing_if = "is_primitive_mode_default"
+    )]
     pub mode: Checked<Mode>,
 
+    /// The raw `mode` value if it is outside of the specification.
+    ///
+    /// Use `ExtensionMode::default()` when constructing a primitive and
+    /// `Primitive::extension_mode` to read the value.
+    #[serde(
+        rename = "mode",
+        default,
+        skip_serializing_if = "ExtensionMode::is_none"
+    )]
+    pub extension_mode: ExtensionMode,
+
     /// An array of Morph Targets, each  Morph Target is a dictionary mapping
     /// attributes (only `POSITION`, `NORMAL`, and `TANGENT` supported) to their
     /// deviations in the Morph Target.
@@ -132,6 +210,518 @@ pub struct Primitive {
     pub targets: Option<Vec<MorphTarget>>,
 }
 
+impl Mesh {
+    /// Returns the morph target names stored in `extras.targetNames`, a
+    /// widespread convention for labelling morph targets.
+    ///
+    /// Returns `None` if the extras have no `targetNames` array of strings.
+    #[cfg(feature = "extras")]
+    pub fn target_names(&self) -> Option<Vec<String>> {
+        #[derive(Deserialize)]
+        struct TargetNames {
+            #[serde(rename = "targetNames")]
+            target_names: Vec<String>,
+        }
+
+        let extras = self.extras.as_ref()?;
+        serde_json::from_str::<TargetNames>(extras.get())
+            .ok()
+            .map(|extras| extras.target_names)
+    }
+
+    /// Performs the advisory checks on this mesh and its primitives.
+    ///
+    /// Failures are reported as warnings by [`crate::Root::validate_report`].
+    pub(crate) fn validate_advisory<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, Error),
+    {
+        // `extras.targetNames` should name every morph target.
+        #[cfg(feature = "extras")]
+        if let Some(names) = self.target_names() {
+            if names.len() != self.morph_target_count() {
+                report(
+                    &|| path().field("extras").field("targetNames"),
+                    Error::Invalid,
+                );
+            }
+        }
+        for (index, primitive) in self.primitives.iter().enumerate() {
+            primitive.validate_advisory(root, || path().field("primitives").index(index), report);
+        }
+    }
+
+    /// Appends the primitives of `other` to this mesh.
+    ///
+    /// The name, extensions, and morph target weights of this mesh are kept,
+    /// falling back to those of `other` where this mesh has none. The extras
+    /// of both meshes are combined according to `extras`.
+    ///
+    /// Both meshes should have the same number of morph targets; see
+    /// [`Mesh::morph_targets_consistent`].
+    pub fn append(&mut self, other: Mesh, extras: crate::extras::ExtrasMerge) {
+        self.primitives.extend(other.primitives);
+        #[cfg(feature = "names")]
+        {
+            self.name = self.name.take().or(other.name);
+        }
+        self.extensions = self.extensions.take().or(other.extensions);
+        self.weights = self.weights.take().or(other.weights);
+        let first = std::mem::take(&mut self.extras);
+        self.extras = crate::extras::merge(first, other.extras, extras);
+    }
+
+    /// Returns the number of morph targets of the first primitive.
+    ///
+    /// A well-formed mesh has the same number of morph targets on every
+    /// primitive; see [`Mesh::morph_targets_consistent`].
+    pub fn morph_target_count(&self) -> usize {
+        self.primitives
+            .first()
+            .map(Primitive::morph_target_count)
+            .unwrap_or(0)
+    }
+
+    /// Returns mutable access to the primitives for editing in place.
+    ///
+    /// Primitives cannot be added or removed through the slice, which keeps
+    /// their number consistent with the rest of the mesh. Edited references
+    /// such as materials or accessors are not checked; validate the root
+    /// afterwards, for example with `gltf::Document::from_json`.
+    pub fn primitives_mut(&mut self) -> &mut [Primitive] {
+        &mut self.primitives
+    }
+
+    /// Returns mutable access to the morph target weights.
+    ///
+    /// The weights are created if absent and resized to the morph target
+    /// count, with any new weights set to zero.
+    pub fn weights_mut(&mut self) -> &mut Vec<f32> {
+        let count = self.morph_target_count();
+        let weights = self.weights.get_or_insert_with(Vec::new);
+        weights.resize(count, 0.0);
+        weights
+    }
+
+    /// Appends a morph target to every primitive of the mesh.
+    ///
+    /// `targets` contains one morph target per primitive, in primitive order.
+    /// The new target is given the initial `weight`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of targets differs from the number of primitives.
+    pub fn push_morph_target(&mut self, targets: Vec<MorphTarget>, weight: f32) {
+        assert_eq!(
+            targets.len(),
+            self.primitives.len(),
+            "expected one morph target per primitive"
+        );
+        let weights = self.weights_mut();
+        weights.push(weight);
+        for (primitive, target) in self.primitives.iter_mut().zip(targets) {
+            primitive.push_morph_target(target);
+        }
+    }
+
+    /// Removes the morph target at `index` from every primitive of the mesh,
+    /// along with its weight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds for any primitive, in which case
+    /// the mesh is left unchanged.
+    pub fn remove_morph_target(&mut self, index: usize) {
+        assert!(
+            self.primitives
+                .iter()
+                .all(|primitive| index < primitive.morph_target_count()),
+            "morph target index {} is out of bounds",
+            index
+        );
+        if let Some(weights) = self.weights.as_mut() {
+            if index < weights.len() {
+                weights.remove(index);
+            }
+        }
+        for primitive in &mut self.primitives {
+            primitive.remove_morph_target(index);
+        }
+    }
+
+    /// Returns `true` if every primitive has the same number of morph targets
+    /// and the weights, when present, match that number.
+    pub fn morph_targets_consistent(&self) -> bool {
+        let count = self.morph_target_count();
+        self.primitives
+            .iter()
+            .all(|primitive| primitive.morph_target_count() == count)
+            && self.weights.as_ref().map_or(count, Vec::len) == count
+    }
+}
+
+impl Primitive {
+    /// Returns the raw `mode` value if it is outside of the specification and
+    /// fits into a `u32`, in which case `mode` is `Checked::Invalid`.
+    ///
+    /// When present, this value is written in place of `mode`. Validation
+    /// requires an extension that this crate does not implement to be listed
+    /// in `extensionsUsed`.
+    #[cfg(feature = "extension_modes")]
+    pub fn extension_mode(&self) -> Option<u32> {
+        self.extension_mode.0.map(|raw| raw as u32)
+    }
+
+    /// Returns whether the attributes required by the specification are
+    /// present, which is only the case for `POSITION`.
+    pub fn has_required_attributes(&self) -> bool {
+        self.attributes
+            .contains_key(&Checked::Valid(Semantic::Positions))
+    }
+
+    /// Visits the material and variant indices of each mapping of the
+    /// `KHR_materials_variants` extension.
+    #[cfg(feature = "KHR_materials_variants")]
+    pub fn variant_mappings(&self) -> impl Iterator<Item = (Index<material::Material>, &[u32])> {
+        self.extensions
+            .iter()
+            .flat_map(|extensions| extensions.khr_materials_variants.iter())
+            .flat_map(|variants| variants.mappings.iter())
+            .map(|mapping| (Index::new(mapping.material), mapping.variants.as_slice()))
+    }
+
+    /// Returns the data checksum stored in `extras.checksum`, if any.
+    ///
+    /// The checksum may be stored as an unsigned integer or, since JSON
+    /// numbers beyond 2^53 are not portable, as a string of hexadecimal
+    /// digits.
+    #[cfg(feature = "extras")]
+    pub fn checksum(&self) -> Option<u64> {
+        #[derive(Deserialize)]
+        struct Checksum {
+            checksum: serde_json::Value,
+        }
+
+        let extras = self.extras.as_ref()?;
+        match serde_json::from_str::<Checksum>(extras.get())
+            .ok()?
+            .checksum
+        {
+            serde_json::Value::Number(number) => number.as_u64(),
+            serde_json::Value::String(digits) => u64::from_str_radix(&digits, 16).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the semantics of the vertex attributes whose accessor index is
+    /// out of range, or whose accessor has an invalid component type or type,
+    /// in canonical order.
+    ///
+    /// This is meant for repairing documents after partial edits; attributes
+    /// with unrecognized semantics are not considered.
+    pub fn attributes_with_missing_accessors(&self, root: &crate::Root) -> Vec<Semantic> {
+        let mut semantics: Vec<Semantic> = self
+            .attributes
+            .iter()
+            .filter_map(|(semantic, index)| {
+                let semantic = semantic.valid()?;
+                let usable = root.get(*index).filter(|accessor| {
+                    accessor.component_type.valid().is_some() && accessor.type_.valid().is_some()
+                });
+                match usable {
+                    Some(_) => None,
+                    None => Some(semantic.clone()),
+                }
+            })
+            .collect();
+        semantics.sort();
+        semantics
+    }
+
+    /// Returns the number of morph targets.
+    pub fn morph_target_count(&self) -> usize {
+        self.targets.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Appends a morph target.
+    ///
+    /// This does not update the weights of the parent mesh; prefer
+    /// [`Mesh::push_morph_target`] to keep the mesh consistent.
+    pub fn push_morph_target(&mut self, target: MorphTarget) {
+        self.targets.get_or_insert_with(Vec::new).push(target);
+    }
+
+    /// Removes and returns the morph target at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_morph_target(&mut self, index: usize) -> MorphTarget {
+        let targets = self
+            .targets
+            .as_mut()
+            .expect("primitive has no morph targets");
+        let target = targets.remove(index);
+        if targets.is_empty() {
+            self.targets = None;
+        }
+        target
+    }
+
+    /// Rewrites every accessor index referenced by the attributes, indices,
+    /// and morph targets of this primitive through an old to new mapping.
+    ///
+    /// Indices absent from `mapping` are left unchanged.
+    pub fn remap_accessors(&mut self, mapping: &HashMap<usize, usize>) {
+        let remap = |index: &mut Index<accessor::Accessor>| {
+            if let Some(&new) = mapping.get(&index.value()) {
+                *index = Index::new(new as u32);
+            }
+        };
+        self.attributes.values_mut().for_each(remap);
+        self.indices.iter_mut().for_each(remap);
+        for target in self.targets.iter_mut().flatten() {
+            target
+                .positions
+                .iter_mut()
+                .chain(target.normals.iter_mut())
+                .chain(target.tangents.iter_mut())
+                .for_each(remap);
+        }
+    }
+
+    /// Returns the attribute set indices grouped by semantic kind, each sorted
+    /// in ascending order.
+    fn attribute_sets(&self) -> Vec<(SemanticKind, Vec<u32>)> {
+        let mut groups: Vec<(SemanticKind, Vec<u32>)> = Vec::new();
+        for semantic in self.attributes.keys().filter_map(Checked::valid) {
+            if let Some((kind, set)) = semantic.split_set() {
+                match groups.iter_mut().find(|(other, _)| other(0) == kind(0)) {
+                    Some((_, sets)) => sets.push(set),
+                    None => groups.push((kind, vec![set])),
+                }
+            }
+        }
+        for (_, sets) in groups.iter_mut() {
+            sets.sort_unstable();
+        }
+        groups
+    }
+
+    /// Renumbers the `COLOR_n`, `TEXCOORD_n`, `JOINTS_n`, and `WEIGHTS_n`
+    /// attribute sets so that each kind is numbered contiguously from 0,
+    /// preserving their relative order.
+    ///
+    /// For example, `TEXCOORD_0` and `TEXCOORD_2` become `TEXCOORD_0` and
+    /// `TEXCOORD_1`. Note that materials referring to renumbered texture
+    /// coordinate sets are not updated. Returns `true` if any attribute was
+    /// renamed.
+    pub fn compact_attribute_sets(&mut self) -> bool {
+        let mut changed = false;
+        for (kind, sets) in self.attribute_sets() {
+            for (new, old) in sets.into_iter().enumerate() {
+                let new = new as u32;
+                if new != old {
+                    let index = self.attributes.remove(&Checked::Valid(kind(old))).unwrap();
+                    self.attributes.insert(Checked::Valid(kind(new)), index);
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Performs the advisory checks on this primitive, whose failures do not
+    /// prevent the data from being used.
+    ///
+    /// Failures are reported as warnings by [`crate::Root::validate_report`].
+    pub(crate) fn validate_advisory<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, Error),
+    {
+        // Buffer views of vertex attributes should target `ARRAY_BUFFER` and
+        // those of indices `ELEMENT_ARRAY_BUFFER`.
+        let expected = self
+            .attributes
+            .values()
+            .map(|index| (*index, buffer::Target::ArrayBuffer))
+            .chain(
+                self.indices
+                    .map(|index| (index, buffer::Target::ElementArrayBuffer)),
+            );
+        for (index, expected) in expected {
+            let view = match root.get(index).and_then(|accessor| accessor.buffer_view) {
+                Some(view) => view,
+                None => continue,
+            };
+            let path = || {
+                crate::Path::new()
+                    .field("bufferViews")
+                    .index(view.value())
+                    .field("target")
+            };
+            match root.get(view).map(|view| view.target) {
+                Some(None) => report(&path, Error::Missing),
+                Some(Some(Checked::Valid(target))) if target != expected => {
+                    report(&path, Error::Invalid)
+                }
+                _ => {}
+            }
+        }
+
+        // Sparse vertex attribute accessors should not be interleaved with
+        // other data, since most consumers expect contiguous base values.
+        for index in self.attributes.values() {
+            let strided = root
+                .get(*index)
+                .filter(|accessor| accessor.sparse.is_some())
+                .and_then(|accessor| accessor.buffer_view)
+                .and_then(|view| root.get(view))
+                .and_then(|view| view.byte_stride)
+                .is_some();
+            if strided {
+                report(
+                    &|| crate::Path::new().field("accessors").index(index.value()),
+                    Error::Invalid,
+                );
+            }
+        }
+
+        // `TANGENT` accessors of non-conformant assets may lack the
+        // handedness, which the `lenient` feature accepts without error.
+        #[cfg(feature = "lenient")]
+        if let Some(accessor) = self
+            .attributes
+            .get(&Checked::Valid(Semantic::Tangents))
+            .and_then(|index| root.get(*index))
+        {
+            if accessor.type_ == Checked::Valid(accessor::Type::Vec3) {
+                report(
+                    &|| path().field("attributes").key("TANGENT"),
+                    Error::Invalid,
+                );
+            }
+        }
+
+        // Morph target POSITION accessors should define `min` and `max`, which
+        // consumers use to compute the bounds of the morphed geometry.
+        let target_positions = self
+            .targets
+            .iter()
+            .flatten()
+            .filter_map(|target| target.positions);
+        for index in target_positions {
+            let accessor = match root.get(index) {
+                Some(accessor) => accessor,
+                None => continue,
+            };
+            let path = || crate::Path::new().field("accessors").index(index.value());
+            if accessor.min.is_none() {
+                report(&|| path().field("min"), Error::Missing);
+            }
+            if accessor.max.is_none() {
+                report(&|| path().field("max"), Error::Missing);
+            }
+        }
+
+        // Morph targets should only displace the attributes that every client
+        // supports.
+        let targets = path().field("targets");
+        for attribute in &root.target_attributes_found {
+            if matches!(
+                attribute.strip_prefix(targets.as_str()),
+                Some(rest) if rest.starts_with('[')
+            ) {
+                report(&|| crate::Path(attribute.clone()), Error::Invalid);
+            }
+        }
+    }
+
+    /// Performs the checks of a validation profile on this primitive.
+    pub(crate) fn validate_profile<P, R>(
+        &self,
+        root: &crate::Root,
+        profile: Profile,
+        path: P,
+        report: &mut R,
+    ) where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, Error),
+    {
+        match profile {
+            Profile::RendererStrict => {
+                let mode = self.mode.valid().copied().unwrap_or_default();
+                if self.indices.is_none() && matches!(mode, Mode::LineLoop | Mode::TriangleFan) {
+                    report(&|| path().field("indices"), Error::Missing);
+                }
+            }
+            Profile::WebGl1 => {
+                // `u32` indices require the `OES_element_index_uint` extension.
+                let component_type = self
+                    .indices
+                    .and_then(|index| root.get(index))
+                    .and_then(|accessor| accessor.component_type.valid());
+                if let Some(accessor::GenericComponentType(accessor::ComponentType::U32)) =
+                    component_type
+                {
+                    report(&|| path().field("indices"), Error::Invalid);
+                }
+                // WebGL 1.0 only guarantees eight vertex attributes.
+                if self.attributes.len() > WEBGL1_MAX_VERTEX_ATTRIBS {
+                    report(&|| path().field("attributes"), Error::Invalid);
+                }
+            }
+        }
+    }
+}
+
+/// Pairs of component type and normalization allowed for a vertex attribute.
+type ComponentTypes = &'static [(accessor::ComponentType, bool)];
+
+/// Returns the accessor types and the pairs of component type and
+/// normalization allowed for vertex attributes of `semantic`.
+///
+/// An empty list of component types allows any. Returns `None` for semantics
+/// without restrictions, such as application-specific attributes. With
+/// `KHR_mesh_quantization`, the component types of positions, normals,
+/// tangents, and texture co-ordinates are not restricted.
+fn attribute_layouts(
+    semantic: &Semantic,
+    quantized: bool,
+) -> Option<(&'static [accessor::Type], ComponentTypes)> {
+    use accessor::ComponentType::{F32, U16, U8};
+    use accessor::Type::{Vec2, Vec3, Vec4};
+
+    const FLOAT: ComponentTypes = &[(F32, false)];
+    const FLOAT_OR_NORMALIZED: ComponentTypes = &[(F32, false), (U8, true), (U16, true)];
+    const UNSIGNED: ComponentTypes = &[(U8, false), (U16, false)];
+    const ANY: ComponentTypes = &[];
+
+    Some(match semantic {
+        Semantic::Positions | Semantic::Normals if quantized => (&[Vec3], ANY),
+        Semantic::Positions | Semantic::Normals => (&[Vec3], FLOAT),
+        Semantic::Tangents if quantized => (&[Vec4], ANY),
+        // `VEC3` tangents are reported as warnings by the advisory checks.
+        #[cfg(feature = "lenient")]
+        Semantic::Tangents => (&[Vec3, Vec4], FLOAT),
+        #[cfg(not(feature = "lenient"))]
+        Semantic::Tangents => (&[Vec4], FLOAT),
+        Semantic::TexCoords(_) if quantized => (&[Vec2], ANY),
+        Semantic::TexCoords(_) => (&[Vec2], FLOAT_OR_NORMALIZED),
+        Semantic::Colors(_) => (&[Vec3, Vec4], FLOAT_OR_NORMALIZED),
+        Semantic::Joints(_) => (&[Vec4], UNSIGNED),
+        Semantic::Weights(_) => (&[Vec4], FLOAT_OR_NORMALIZED),
+        #[allow(unreachable_patterns)]
+        _ => return None,
+    })
+}
+
+/// The number of vertex attributes that every WebGL 1.0 implementation
+/// supports.
+const WEBGL1_MAX_VERTEX_ATTRIBS: usize = 8;
+
 fn is_primitive_mode_default(mode: &Checked<Mode>) -> bool {
     *mode == Checked::Valid(Mode::Triangles)
 }
@@ -153,12 +743,29 @@ impl Validate for Primitive {
             .validate(root, || path().field("indices"), report);
         self.material
             .validate(root, || path().field("material"), report);
-        self.mode.validate(root, || path().field("mode"), report);
+        if self.extension_mode.is_none() {
+            self.mode.validate(root, || path().field("mode"), report);
+        }
         self.targets
             .validate(root, || path().field("targets"), report);
 
         // Custom part
         let position_path = &|| path().field("attributes").key("POSITION");
+        if !self.has_required_attributes() {
+            report(position_path, Error::Missing);
+        }
+
+        // Modes outside of the specification may only be added by extensions
+        // unknown to this crate.
+        if !self.extension_mode.is_none() {
+            let declared = root
+                .extensions_used
+                .iter()
+                .any(|name| !extensions::SUPPORTED_EXTENSIONS.contains(&name.as_str()));
+            if !declared {
+                report(&|| path().field("mode"), Error::Invalid);
+            }
+        }
         if let Some(pos_accessor_index) = self.attributes.get(&Checked::Valid(Semantic::Positions))
         {
             // spec: POSITION accessor **must** have `min` and `max` properties defined.
@@ -181,14 +788,166 @@ impl Validate for Primitive {
             } else {
                 report(max_path, Error::Missing);
             }
-        } else {
-            report(position_path, Error::Missing);
+        }
+
+        // spec: all vertex attribute accessors **must** have the same count.
+        let position_count = self
+            .attributes
+            .get(&Checked::Valid(Semantic::Positions))
+            .and_then(|index| root.get(*index))
+            .map(|accessor| accessor.count);
+        if let Some(position_count) = position_count {
+            for (semantic, index) in self.attributes.iter() {
+                let semantic = match semantic {
+                    Checked::Valid(semantic) => semantic,
+                    Checked::Invalid => continue,
+                };
+                if let Some(accessor) = root.get(*index) {
+                    if accessor.count != position_count {
+                        let key = semantic.to_string();
+                        report(&|| path().field("attributes").key(&key), Error::Invalid);
+                    }
+                }
+            }
+        }
+
+        // Index and attribute accessors must not be viewed through buffer views
+        // targeting the other kind of GPU buffer.
+        let target = |index: Index<accessor::Accessor>| {
+            root.get(index)
+                .and_then(|accessor| accessor.buffer_view)
+                .and_then(|view| root.get(view))
+                .and_then(|view| view.target.as_ref())
+                .and_then(|target| target.valid().copied())
+        };
+        if let Some(indices) = self.indices {
+            if target(indices) == Some(buffer::Target::ArrayBuffer) {
+                report(&|| path().field("indices"), Error::Invalid);
+            }
+        }
+        for (semantic, index) in self.attributes.iter() {
+            if let Checked::Valid(semantic) = semantic {
+                if target(*index) == Some(buffer::Target::ElementArrayBuffer) {
+                    let key = semantic.to_string();
+                    report(&|| path().field("attributes").key(&key), Error::Invalid);
+                }
+            }
+        }
+
+        // Application-specific attribute names must not be empty.
+        #[cfg(feature = "extras")]
+        for semantic in self.attributes.keys() {
+            if let Checked::Valid(Semantic::Extras(ref name)) = semantic {
+                if name.is_empty() {
+                    report(&|| path().field("attributes").key("_"), Error::Invalid);
+                }
+            }
+        }
+
+        // spec: indexed attribute sets **must** start with 0 and be contiguous.
+        for (kind, sets) in self.attribute_sets() {
+            let count = sets.len() as u32;
+            for set in sets.into_iter().filter(|&set| set >= count) {
+                let key = kind(set).to_string();
+                report(&|| path().field("attributes").key(&key), Error::Invalid);
+            }
+        }
+
+        // spec: vertex attribute accessors **must** have one of the types and
+        // component types listed for their semantic.
+        let quantized = root
+            .extensions_used
+            .iter()
+            .any(|name| name == "KHR_mesh_quantization");
+        for (semantic, index) in self.attributes.iter() {
+            let (semantic, accessor) = match (semantic, root.get(*index)) {
+                (Checked::Valid(semantic), Some(accessor)) => (semantic, accessor),
+                _ => continue,
+            };
+            let (types, components) = match attribute_layouts(semantic, quantized) {
+                Some(layouts) => layouts,
+                None => continue,
+            };
+            let type_valid = accessor
+                .type_
+                .valid()
+                .filter(|type_| types.contains(type_))
+                .is_some();
+            let component_valid = components.is_empty()
+                || accessor
+                    .component_type
+                    .valid()
+                    .filter(|component_type| {
+                        components.contains(&(component_type.0, accessor.normalized))
+                    })
+                    .is_some();
+            if !type_valid || !component_valid {
+                let key = semantic.to_string();
+                report(&|| path().field("attributes").key(&key), Error::Invalid);
+            }
+        }
+
+        // spec: index accessors **must** be unsigned integer `SCALAR`s.
+        if let Some(accessor) = self.indices.and_then(|index| root.get(index)) {
+            let is_unsigned_scalar = accessor.type_ == Checked::Valid(accessor::Type::Scalar)
+                && matches!(
+                    accessor.component_type,
+                    Checked::Valid(accessor::GenericComponentType(
+                        accessor::ComponentType::U8
+                            | accessor::ComponentType::U16
+                            | accessor::ComponentType::U32
+                    ))
+                );
+            if !is_unsigned_scalar {
+                report(&|| path().field("indices"), Error::Invalid);
+            }
+        }
+
+        // spec: morph target displacements **must** be `f32` `VEC3` with the
+        // same number of elements as the base attribute. With
+        // `KHR_mesh_quantization`, they may also be signed bytes or shorts,
+        // which must be normalized for normals and tangents.
+        for (index, target) in self.targets.iter().flatten().enumerate() {
+            let displacements = [
+                (Semantic::Positions, target.positions),
+                (Semantic::Normals, target.normals),
+                (Semantic::Tangents, target.tangents),
+            ];
+            for (semantic, displacement) in displacements.iter() {
+                let accessor = match displacement.and_then(|index| root.get(index)) {
+                    Some(accessor) => accessor,
+                    None => continue,
+                };
+                let component_valid = match accessor.component_type {
+                    Checked::Valid(accessor::GenericComponentType(
+                        accessor::ComponentType::F32,
+                    )) => true,
+                    Checked::Valid(accessor::GenericComponentType(
+                        accessor::ComponentType::I8 | accessor::ComponentType::I16,
+                    )) if quantized => accessor.normalized || *semantic == Semantic::Positions,
+                    _ => false,
+                };
+                let is_vec3 = accessor.type_ == Checked::Valid(accessor::Type::Vec3);
+                let matches_base = self
+                    .attributes
+                    .get(&Checked::Valid(semantic.clone()))
+                    .and_then(|index| root.get(*index))
+                    .map_or(accessor.count, |base| base.count)
+                    == accessor.count;
+                if !component_valid || !is_vec3 || !matches_base {
+                    let key = semantic.to_string();
+                    report(
+                        &|| path().field("targets").index(index).key(&key),
+                        Error::Invalid,
+                    );
+                }
+            }
         }
     }
 }
 
 /// A dictionary mapping attributes to their deviations in the Morph Target.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Serialize, Validate)]
 pub struct MorphTarget {
     /// XYZ vertex position displacements of type `[f32; 3]`.
     #[serde(rename = "POSITION")]
@@ -206,6 +965,111 @@ pub struct MorphTarget {
     pub tangents: Option<Index<accessor::Accessor>>,
 }
 
+impl<'de> de::Deserialize<'de> for MorphTarget {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Fields {
+            #[serde(rename = "POSITION")]
+            positions: Option<Index<accessor::Accessor>>,
+            #[serde(rename = "NORMAL")]
+            normals: Option<Index<accessor::Accessor>>,
+            #[serde(rename = "TANGENT")]
+            tangents: Option<Index<accessor::Accessor>>,
+            #[serde(flatten)]
+            others: HashMap<String, de::IgnoredAny>,
+        }
+
+        let fields = <Fields as de::Deserialize>::deserialize(deserializer)?;
+        // Attributes that clients are not required to support, such as
+        // `TEXCOORD_n` or `COLOR_n`, are reported by
+        // `Primitive::validate_advisory`.
+        let mut others: Vec<String> = fields.others.into_keys().collect();
+        others.sort();
+        extensions::record_target_attributes(others);
+        Ok(MorphTarget {
+            positions: fields.positions,
+            normals: fields.normals,
+            tangents: fields.tangents,
+        })
+    }
+}
+
+/// Returns the paths of the recorded attributes of every morph target of
+/// `meshes`, given in the order the morph targets were deserialized.
+pub(crate) fn target_attribute_paths(meshes: &[Mesh], recorded: Vec<Vec<String>>) -> Vec<String> {
+    let targets: Vec<crate::Path> = meshes
+        .iter()
+        .enumerate()
+        .flat_map(|(mesh_index, mesh)| {
+            mesh.primitives
+                .iter()
+                .enumerate()
+                .flat_map(move |(primitive_index, primitive)| {
+                    let count = primitive.targets.as_ref().map_or(0, Vec::len);
+                    (0..count).map(move |target_index| {
+                        crate::Path::new()
+                            .field("meshes")
+                            .index(mesh_index)
+                            .field("primitives")
+                            .index(primitive_index)
+                            .field("targets")
+                            .index(target_index)
+                    })
+                })
+        })
+        .collect();
+    // Morph targets deserialized outside of `meshes` would misalign the two.
+    if targets.len() != recorded.len() {
+        return Vec::new();
+    }
+    targets
+        .iter()
+        .zip(recorded)
+        .flat_map(|(path, keys)| keys.into_iter().map(move |key| path.key(&key).0))
+        .collect()
+}
+
+impl MorphTarget {
+    /// Returns a builder for a morph target that displaces no attributes.
+    pub fn builder() -> MorphTargetBuilder {
+        MorphTargetBuilder::default()
+    }
+}
+
+/// Builds a [`MorphTarget`] one displaced attribute at a time.
+#[derive(Clone, Debug, Default)]
+pub struct MorphTargetBuilder {
+    target: MorphTarget,
+}
+
+impl MorphTargetBuilder {
+    /// Sets the accessor of the vertex position displacements.
+    pub fn positions(mut self, accessor: Index<accessor::Accessor>) -> Self {
+        self.target.positions = Some(accessor);
+        self
+    }
+
+    /// Sets the accessor of the vertex normal displacements.
+    pub fn normals(mut self, accessor: Index<accessor::Accessor>) -> Self {
+        self.target.normals = Some(accessor);
+        self
+    }
+
+    /// Sets the accessor of the vertex tangent displacements.
+    pub fn tangents(mut self, accessor: Index<accessor::Accessor>) -> Self {
+        self.target.tangents = Some(accessor);
+        self
+    }
+
+    /// Returns the morph target.
+    pub fn build(self) -> MorphTarget {
+        self.target
+    }
+}
+
 /// Vertex attribute semantic name.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Semantic {
@@ -234,6 +1098,10 @@ pub enum Semantic {
 
     /// Joint weights.
     Weights(u32),
+
+    /// Feature IDs as defined by the `EXT_mesh_features` extension.
+    #[cfg(feature = "EXT_mesh_features")]
+    FeatureIds(u32),
 }
 
 impl Default for Mode {
@@ -243,6 +1111,30 @@ impl Default for Mode {
 }
 
 impl Mode {
+    /// Returns the mode corresponding to a `GLenum`, if any.
+    pub fn from_gl_enum(value: u32) -> Option<Self> {
+        match value {
+            POINTS => Some(Mode::Points),
+            LINES => Some(Mode::Lines),
+            LINE_LOOP => Some(Mode::LineLoop),
+            LINE_STRIP => Some(Mode::LineStrip),
+            TRIANGLES => Some(Mode::Triangles),
+            TRIANGLE_STRIP => Some(Mode::TriangleStrip),
+            TRIANGLE_FAN => Some(Mode::TriangleFan),
+            _ => None,
+        }
+    }
+
+    /// Returns the position of this mode in `VALID_MODES`.
+    ///
+    /// See [`mode_from_valid_index`] for the reverse mapping.
+    pub fn valid_index(self) -> usize {
+        VALID_MODES
+            .iter()
+            .position(|&mode| mode == self.as_gl_enum())
+            .unwrap()
+    }
+
     /// Returns the equivalent `GLenum`.
     pub fn as_gl_enum(self) -> u32 {
         match self {
@@ -257,6 +1149,52 @@ impl Mode {
     }
 }
 
+impl<'de> de::Deserialize<'de> for Primitive {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let primitive = Primitive::deserialize(deserializer)?;
+        let (mode, extension_mode) = match primitive.extension_mode.0 {
+            Some(raw) => {
+                // A plain cast would truncate out-of-range values onto valid modes.
+                let value = u32::try_from(raw).ok();
+                match value.and_then(Mode::from_gl_enum) {
+                    Some(mode) => (Checked::Valid(mode), ExtensionMode::default()),
+                    None if cfg!(feature = "extension_modes") && value.is_some() => {
+                        (Checked::Invalid, primitive.extension_mode)
+                    }
+                    None => (Checked::Invalid, ExtensionMode::default()),
+                }
+            }
+            None => (Checked::Valid(Mode::Triangles), ExtensionMode::default()),
+        };
+        Ok(Primitive {
+            mode,
+            extension_mode,
+            ..primitive
+        })
+    }
+}
+
+impl ser::Serialize for Primitive {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if self.extension_mode.is_none() {
+            Primitive::serialize(self, serializer)
+        } else {
+            // Skip `mode`, which the extension mode is written in place of.
+            let primitive = Primitive {
+                mode: Checked::Valid(Mode::Triangles),
+                ..self.clone()
+            };
+            Primitive::serialize(&primitive, serializer)
+        }
+    }
+}
+
 impl<'de> de::Deserialize<'de> for Checked<Mode> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -274,18 +1212,11 @@ impl<'de> de::Deserialize<'de> for Checked<Mode> {
             where
                 E: de::Error,
             {
-                use self::Mode::*;
-                use crate::validation::Checked::*;
-                Ok(match value as u32 {
-                    POINTS => Valid(Points),
-                    LINES => Valid(Lines),
-                    LINE_LOOP => Valid(LineLoop),
-                    LINE_STRIP => Valid(LineStrip),
-                    TRIANGLES => Valid(Triangles),
-                    TRIANGLE_STRIP => Valid(TriangleStrip),
-                    TRIANGLE_FAN => Valid(TriangleFan),
-                    _ => Invalid,
-                })
+                // A plain cast would truncate out-of-range values onto valid modes.
+                let value = u32::try_from(value).ok();
+                Ok(value
+                    .and_then(Mode::from_gl_enum)
+                    .map_or(Checked::Invalid, Checked::Valid))
             }
         }
         deserializer.deserialize_u64(Visitor)
@@ -309,6 +1240,11 @@ impl Semantic {
             "NORMAL" => Valid(Normals),
             "POSITION" => Valid(Positions),
             "TANGENT" => Valid(Tangents),
+            #[cfg(feature = "EXT_mesh_features")]
+            _ if s.starts_with("_FEATURE_ID_") => match s["_FEATURE_ID_".len()..].parse() {
+                Ok(set) => Valid(FeatureIds(set)),
+                Err(_) => Invalid,
+            },
             #[cfg(feature = "extras")]
             _ if s.starts_with('_') => Valid(Extras(s[1..].to_string())),
             _ if s.starts_with("COLOR_") => match s["COLOR_".len()..].parse() {
@@ -332,6 +1268,58 @@ impl Semantic {
     }
 }
 
+impl Semantic {
+    /// Returns the rank, set index, and name used for canonical ordering.
+    fn sort_key(&self) -> (u32, u32, &str) {
+        use self::Semantic::*;
+        match *self {
+            Positions => (0, 0, ""),
+            Normals => (1, 0, ""),
+            Tangents => (2, 0, ""),
+            Colors(set) => (3, set, ""),
+            TexCoords(set) => (4, set, ""),
+            Joints(set) => (5, set, ""),
+            Weights(set) => (6, set, ""),
+            #[cfg(feature = "EXT_mesh_features")]
+            FeatureIds(set) => (7, set, ""),
+            #[cfg(feature = "extras")]
+            Extras(ref name) => (8, 0, name),
+        }
+    }
+}
+
+/// Semantics are ordered canonically: `POSITION`, `NORMAL`, `TANGENT`,
+/// `COLOR_n`, `TEXCOORD_n`, `JOINTS_n`, `WEIGHTS_n`, then extension and
+/// application-specific semantics, with sets in ascending order.
+impl Ord for Semantic {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl PartialOrd for Semantic {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Constructor of an indexed semantic such as `Semantic::TexCoords`.
+type SemanticKind = fn(u32) -> Semantic;
+
+impl Semantic {
+    /// Splits an indexed core semantic such as `TEXCOORD_n` into a
+    /// constructor and its set index.
+    fn split_set(&self) -> Option<(SemanticKind, u32)> {
+        match *self {
+            Semantic::Colors(set) => Some((Semantic::Colors, set)),
+            Semantic::TexCoords(set) => Some((Semantic::TexCoords, set)),
+            Semantic::Joints(set) => Some((Semantic::Joints, set)),
+            Semantic::Weights(set) => Some((Semantic::Weights, set)),
+            _ => None,
+        }
+    }
+}
+
 impl ser::Serialize for Semantic {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -352,6 +1340,8 @@ impl ToString for Semantic {
             TexCoords(set) => format!("TEXCOORD_{}", set),
             Joints(set) => format!("JOINTS_{}", set),
             Weights(set) => format!("WEIGHTS_{}", set),
+            #[cfg(feature = "EXT_mesh_features")]
+            FeatureIds(set) => format!("_FEATURE_ID_{}", set),
             #[cfg(feature = "extras")]
             Extras(ref name) => format!("_{}", name),
         }
@@ -1,5 +1,6 @@
 use crate::buffer;
 use crate::extensions;
+use crate::mesh;
 use crate::texture;
 use crate::validation;
 use gltf_derive::Validate;
@@ -24,6 +25,8 @@ pub struct Index<T>(u32, marker::PhantomData<fn() -> T>);
 
 /// The root object of a glTF 2.0 asset.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(remote = "Self")]
+#[gltf(validate_hook = "root_validate_hook")]
 pub struct Root {
     /// An array of accessors.
     #[serde(default)]
@@ -53,7 +56,11 @@ pub struct Root {
     pub scene: Option<Index<Scene>>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::root::Root>,
 
     /// Optional application specific data.
@@ -116,6 +123,70 @@ pub struct Root {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub textures: Vec<Texture>,
+
+    /// Storage for `Root::extensions_present`, recorded during
+    /// deserialization.
+    #[doc(hidden)]
+    #[serde(skip)]
+    pub extensions_found: Vec<String>,
+
+    /// Storage for the paths of the morph target attributes other than
+    /// `POSITION`, `NORMAL` and `TANGENT`, recorded during deserialization.
+    #[doc(hidden)]
+    #[serde(skip)]
+    pub target_attributes_found: Vec<String>,
+}
+
+impl<'de> serde::Deserialize<'de> for Root {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (root, recorded) = extensions::record(|| Root::deserialize(deserializer));
+        root.map(|root| Root {
+            extensions_found: recorded.extensions,
+            target_attributes_found: mesh::target_attribute_paths(
+                &root.meshes,
+                recorded.target_attributes,
+            ),
+            ..root
+        })
+    }
+}
+
+impl serde::Serialize for Root {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Root::serialize(self, serializer)
+    }
+}
+
+/// The custom part of `Validate for Root`.
+fn root_validate_hook<P, R>(this: &Root, _root: &Root, path: P, report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, validation::Error),
+{
+    // spec: every extension used by the asset **must** be listed in
+    // `extensionsUsed`.
+    for name in this.extensions_present() {
+        if !this.extensions_used.contains(name) {
+            report(
+                &|| path().field("extensionsUsed").key(name),
+                validation::Error::Missing,
+            );
+        }
+    }
+
+    // Morph target weights must be finite, but number literals too large for
+    // an `f32` deserialize to infinity.
+    this.for_each_weight(&path, |path, weight| {
+        if !weight.is_finite() {
+            report(path, validation::Error::Invalid);
+        }
+    });
 }
 
 impl Root {
@@ -127,6 +198,126 @@ impl Root {
         (self as &dyn Get<T>).get(index)
     }
 
+    /// Visits the path, prefixed with `path`, and value of every morph target
+    /// weight of the meshes and nodes.
+    fn for_each_weight<P, F>(&self, path: P, mut f: F)
+    where
+        P: Fn() -> Path,
+        F: FnMut(&dyn Fn() -> Path, f32),
+    {
+        let meshes = self
+            .meshes
+            .iter()
+            .map(|mesh| &mesh.weights)
+            .enumerate()
+            .map(|(index, weights)| ("meshes", index, weights));
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| &node.weights)
+            .enumerate()
+            .map(|(index, weights)| ("nodes", index, weights));
+        for (field, index, weights) in meshes.chain(nodes) {
+            for (weight_index, &weight) in weights.iter().flatten().enumerate() {
+                f(
+                    &|| {
+                        path()
+                            .field(field)
+                            .index(index)
+                            .field("weights")
+                            .index(weight_index)
+                    },
+                    weight,
+                );
+            }
+        }
+    }
+
+    /// Returns the sorted names of the extensions present on any object of
+    /// the asset.
+    ///
+    /// The names are recorded during deserialization, so this includes
+    /// extensions that are not supported by the enabled features but is
+    /// empty for a root that was built in code.
+    pub fn extensions_present(&self) -> &[String] {
+        &self.extensions_found
+    }
+
+    /// Performs the specification checks of [`Validate::validate`] and a set
+    /// of advisory checks, reporting each failure with the severity
+    /// configured by `severities`.
+    ///
+    /// Specification checks default to `Severity::Error`. Advisory checks
+    /// flag data that is valid but may be inconvenient for consumers, such as
+    /// vertex attribute buffer views without an `ARRAY_BUFFER` target, and
+    /// default to `Severity::Warning`.
+    pub fn validate_report(&self, severities: &validation::Severities) -> validation::Report {
+        let mut report = validation::Report::default();
+        let mut push = |path: Path, error, default| {
+            let severity = severities.severity(&path, error, default);
+            report.issues.push(validation::Issue {
+                path,
+                error,
+                severity,
+            });
+        };
+        self.validate(self, Path::new, &mut |path, error| {
+            push(path(), error, validation::Severity::Error)
+        });
+
+        // Advisory checks may concern data shared between primitives, so
+        // each failure is only reported once.
+        let mut reported = std::collections::HashSet::new();
+        for (index, mesh) in self.meshes.iter().enumerate() {
+            mesh.validate_advisory(
+                self,
+                || Path::new().field("meshes").index(index),
+                &mut |path, error| {
+                    let path = path();
+                    if reported.insert((path.as_str().to_owned(), error)) {
+                        push(path, error, validation::Severity::Warning);
+                    }
+                },
+            );
+        }
+        report
+    }
+
+    /// Performs the additional checks of a validation profile.
+    ///
+    /// This is independent of [`Validate::validate`] and assumes the data
+    /// has already passed the specification checks.
+    pub fn validate_profile<R>(&self, profile: validation::Profile, report: &mut R)
+    where
+        R: FnMut(&dyn Fn() -> Path, validation::Error),
+    {
+        // Negative morph target weights extrapolate beyond the targets, which
+        // renderers often do not expect.
+        if profile == validation::Profile::RendererStrict {
+            self.for_each_weight(Path::new, |path, weight| {
+                if weight < 0.0 {
+                    report(path, validation::Error::Invalid);
+                }
+            });
+        }
+        for (i, mesh) in self.meshes.iter().enumerate() {
+            for (j, primitive) in mesh.primitives.iter().enumerate() {
+                primitive.validate_profile(
+                    self,
+                    profile,
+                    || {
+                        Path::new()
+                            .field("meshes")
+                            .index(i)
+                            .field("primitives")
+                            .index(j)
+                    },
+                    report,
+                );
+            }
+        }
+    }
+
     /// Deserialize from a JSON string slice.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(str_: &str) -> Result<Self, Error> {
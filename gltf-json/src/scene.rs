@@ -24,7 +24,11 @@ pub struct Node {
     pub children: Option<Vec<Index<scene::Node>>>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::scene::Node>,
 
     /// Optional application specific data.
@@ -82,7 +86,11 @@ pub struct Node {
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Scene {
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::scene::Scene>,
 
     /// Optional application specific data.
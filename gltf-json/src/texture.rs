@@ -156,7 +156,11 @@ pub struct Sampler {
     pub wrap_t: Checked<WrappingMode>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::texture::Sampler>,
 
     /// Optional application specific data.
@@ -182,7 +186,11 @@ pub struct Texture {
     pub source: Index<image::Image>,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::texture::Texture>,
 
     /// Optional application specific data.
@@ -203,7 +211,11 @@ pub struct Info {
     pub tex_coord: u32,
 
     /// Extension specific data.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "crate::extensions::recorded",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extensions: Option<extensions::texture::Info>,
 
     /// Optional application specific data.
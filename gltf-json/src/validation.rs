@@ -29,6 +29,109 @@ pub enum Error {
     Missing,
 }
 
+/// An opt-in set of checks that go beyond the requirements of the glTF
+/// specification.
+///
+/// Profiles flag data that is valid glTF but that particular consumers
+/// commonly cannot handle. See [`Root::validate_profile`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Profile {
+    /// Flags constructs that renderers commonly cannot draw directly, such as
+    /// non-indexed line loops and triangle fans.
+    RendererStrict,
+
+    /// Flags primitives that WebGL 1.0 cannot draw without extensions, such
+    /// as those with `u32` indices or more than eight vertex attributes.
+    WebGl1,
+}
+
+/// How seriously a validation failure should be taken.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The data cannot be used safely.
+    Error,
+
+    /// The data is usable but may cause problems for some consumers.
+    Warning,
+}
+
+/// Configures the severity at which validation failures are reported.
+///
+/// Failures are matched by their kind and by the end of their JSON path, so
+/// that for example `Error::Missing` with the suffix `.min` matches every
+/// missing `min` property. Later rules take precedence over earlier ones, and
+/// failures matched by no rule keep their default severity.
+#[derive(Clone, Debug, Default)]
+pub struct Severities {
+    rules: Vec<(Error, String, Severity)>,
+}
+
+impl Severities {
+    /// Creates a configuration that keeps every default severity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reports failures of kind `error` at paths ending with `path_suffix`
+    /// with the given severity.
+    pub fn set(mut self, error: Error, path_suffix: &str, severity: Severity) -> Self {
+        self.rules.push((error, path_suffix.to_owned(), severity));
+        self
+    }
+
+    /// Returns the configured severity of a failure, or `default` if no rule
+    /// matches it.
+    pub fn severity(&self, path: &Path, error: Error, default: Severity) -> Severity {
+        self.rules
+            .iter()
+            .rev()
+            .find(|(kind, suffix, _)| *kind == error && path.as_str().ends_with(suffix.as_str()))
+            .map(|(_, _, severity)| *severity)
+            .unwrap_or(default)
+    }
+}
+
+/// A validation failure along with its severity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Issue {
+    /// The JSON path of the offending data.
+    pub path: Path,
+
+    /// The kind of failure.
+    pub error: Error,
+
+    /// The configured severity of the failure.
+    pub severity: Severity,
+}
+
+/// The result of [`Root::validate_report`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// Every failure, in the order it was found.
+    pub issues: Vec<Issue>,
+}
+
+impl Report {
+    /// Returns an `Iterator` that visits the failures of `Error` severity.
+    pub fn errors(&self) -> impl Iterator<Item = &Issue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+    }
+
+    /// Returns an `Iterator` that visits the failures of `Warning` severity.
+    pub fn warnings(&self) -> impl Iterator<Item = &Issue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Warning)
+    }
+
+    /// Returns `true` if any failure has `Error` severity.
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+}
+
 /// Specifies a type that has been pre-validated during deserialization or otherwise.
 #[derive(Debug, Eq, Hash, PartialEq)]
 pub enum Checked<T> {
@@ -48,6 +151,15 @@ impl<T> Checked<T> {
         }
     }
 
+    /// Returns a reference to the contained item if it is `Valid`, or `None`
+    /// otherwise.
+    pub fn valid(&self) -> Option<&T> {
+        match *self {
+            Checked::Valid(ref item) => Some(item),
+            Checked::Invalid => None,
+        }
+    }
+
     /// Takes ownership of the contained item if it is `Valid`.
     ///
     /// # Panics
@@ -177,3 +289,21 @@ impl Validate for [f32; 16] {}
 impl Validate for () {}
 impl Validate for String {}
 impl Validate for serde_json::Value {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::Semantic;
+
+    #[test]
+    fn checked_valid() {
+        let checked = Checked::Valid(Semantic::TexCoords(1));
+        assert_eq!(checked.valid(), Some(&Semantic::TexCoords(1)));
+    }
+
+    #[test]
+    fn checked_invalid() {
+        let checked = Checked::<Semantic>::Invalid;
+        assert_eq!(checked.valid(), None);
+    }
+}
@@ -0,0 +1,25 @@
+use gltf_json::accessor::Accessor;
+use serde_json::json;
+
+fn element_byte_size(component_type: u32, type_: &str) -> Option<usize> {
+    let accessor: Accessor = serde_json::from_value(json!({
+        "bufferView": 0,
+        "componentType": component_type,
+        "count": 1,
+        "type": type_,
+    }))
+    .unwrap();
+    accessor.element_byte_size()
+}
+
+#[test]
+fn test_element_byte_size() {
+    assert_eq!(element_byte_size(5126, "VEC3"), Some(12));
+    assert_eq!(element_byte_size(5121, "VEC4"), Some(4));
+    assert_eq!(element_byte_size(5126, "MAT4"), Some(64));
+    // Matrix columns are padded to 4 bytes.
+    assert_eq!(element_byte_size(5121, "MAT2"), Some(8));
+    assert_eq!(element_byte_size(5121, "MAT3"), Some(12));
+    assert_eq!(element_byte_size(5123, "MAT3"), Some(24));
+    assert_eq!(element_byte_size(5126, "VEC5"), None);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "lenient")]
+
+use gltf_json::mesh::Mesh;
+use serde_json::json;
+
+#[test]
+fn test_single_primitive_object() {
+    let primitive = json!({ "attributes": { "POSITION": 0 } });
+    let mesh: Mesh = serde_json::from_value(json!({ "primitives": primitive })).unwrap();
+    assert_eq!(mesh.primitives.len(), 1);
+    let mesh: Mesh =
+        serde_json::from_value(json!({ "primitives": [primitive, primitive] })).unwrap();
+    assert_eq!(mesh.primitives.len(), 2);
+}
+
+#[test]
+fn test_stringified_attribute_index() {
+    use gltf_json::mesh::{Primitive, Semantic};
+    use gltf_json::validation::Checked;
+
+    let primitive: Primitive =
+        serde_json::from_value(json!({ "attributes": { "POSITION": "0", "NORMAL": 1 } })).unwrap();
+    let index = |semantic| primitive.attributes[&Checked::Valid(semantic)].value();
+    assert_eq!(index(Semantic::Positions), 0);
+    assert_eq!(index(Semantic::Normals), 1);
+    assert!(
+        serde_json::from_value::<Primitive>(json!({ "attributes": { "POSITION": "zero" } }))
+            .is_err()
+    );
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_primitives_with_extras() {
+    let primitive = json!({ "attributes": { "POSITION": 0 }, "extras": { "checksum": 1 } });
+    let mesh: Mesh = serde_json::from_value(json!({ "primitives": [primitive] })).unwrap();
+    assert_eq!(mesh.primitives.len(), 1);
+    let text = json!({ "primitives": primitive }).to_string();
+    let mesh: Mesh = serde_json::from_str(&text).unwrap();
+    assert_eq!(
+        mesh.primitives[0].extras.as_ref().unwrap().get(),
+        r#"{"checksum":1}"#
+    );
+}
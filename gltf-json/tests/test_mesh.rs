@@ -0,0 +1,395 @@
+use gltf_json::mesh::{Mesh, MorphTarget};
+use gltf_json::Index;
+use serde_json::json;
+
+fn mesh(primitive_count: usize) -> Mesh {
+    let primitive = json!({ "attributes": { "POSITION": 0 } });
+    let primitives = vec![primitive; primitive_count];
+    serde_json::from_value(json!({ "primitives": primitives })).unwrap()
+}
+
+fn target(positions: u32) -> MorphTarget {
+    MorphTarget {
+        positions: Some(Index::new(positions)),
+        normals: None,
+        tangents: None,
+    }
+}
+
+#[test]
+fn test_push_morph_target_resizes_weights() {
+    let mut mesh = mesh(2);
+    assert_eq!(mesh.morph_target_count(), 0);
+    assert!(mesh.weights_mut().is_empty());
+
+    mesh.push_morph_target(vec![target(1), target(2)], 0.5);
+    mesh.push_morph_target(vec![target(3), target(4)], 0.25);
+    assert_eq!(mesh.morph_target_count(), 2);
+    assert_eq!(mesh.weights, Some(vec![0.5, 0.25]));
+    assert!(mesh.morph_targets_consistent());
+
+    mesh.remove_morph_target(0);
+    assert_eq!(mesh.weights, Some(vec![0.25]));
+    let targets = mesh.primitives[1].targets.as_ref().unwrap();
+    assert_eq!(targets[0].positions, Some(Index::new(4)));
+    assert!(mesh.morph_targets_consistent());
+}
+
+#[test]
+fn test_remove_morph_target_out_of_bounds_leaves_mesh_unchanged() {
+    let mut mesh = mesh(2);
+    mesh.push_morph_target(vec![target(1), target(2)], 0.5);
+    mesh.primitives[0].push_morph_target(target(3));
+    mesh.weights = Some(vec![0.5, 0.25]);
+
+    let result =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| mesh.remove_morph_target(1)));
+    assert!(result.is_err());
+    assert_eq!(mesh.weights, Some(vec![0.5, 0.25]));
+    assert_eq!(mesh.primitives[0].morph_target_count(), 2);
+    assert_eq!(mesh.primitives[1].morph_target_count(), 1);
+}
+
+#[test]
+fn test_morph_target_builder() {
+    let target = MorphTarget::builder().positions(Index::new(3)).build();
+    assert_eq!(
+        serde_json::to_value(&target).unwrap(),
+        json!({ "POSITION": 3 })
+    );
+
+    let target = MorphTarget::builder()
+        .positions(Index::new(0))
+        .normals(Index::new(1))
+        .tangents(Index::new(2))
+        .build();
+    assert_eq!(
+        serde_json::to_value(&target).unwrap(),
+        json!({ "POSITION": 0, "NORMAL": 1, "TANGENT": 2 })
+    );
+}
+
+#[test]
+fn test_weights_mut_pads_missing_weights() {
+    let mut mesh = mesh(1);
+    mesh.primitives[0].push_morph_target(target(1));
+    mesh.primitives[0].push_morph_target(target(2));
+    assert!(mesh.morph_targets_consistent());
+
+    mesh.weights = Some(vec![1.0]);
+    assert!(!mesh.morph_targets_consistent());
+    mesh.weights_mut()[1] = 0.75;
+    assert_eq!(mesh.weights, Some(vec![1.0, 0.75]));
+    assert!(mesh.morph_targets_consistent());
+}
+
+#[test]
+fn test_morph_targets_inconsistent_between_primitives() {
+    let mut mesh = mesh(2);
+    mesh.primitives[0].push_morph_target(target(1));
+    assert!(!mesh.morph_targets_consistent());
+}
+
+#[test]
+fn test_absent_mode_defaults_to_triangles() {
+    use gltf_json::mesh::{Mode, Primitive};
+    use gltf_json::validation::Checked;
+
+    let primitive: Primitive =
+        serde_json::from_value(json!({ "attributes": { "POSITION": 0 } })).unwrap();
+    assert_eq!(primitive.mode, Checked::Valid(Mode::Triangles));
+
+    let value = serde_json::to_value(&primitive).unwrap();
+    assert!(value.get("mode").is_none());
+}
+
+#[test]
+fn test_mode_valid_index_round_trip() {
+    use gltf_json::mesh::{mode_from_valid_index, VALID_MODES};
+
+    for (index, &gl_enum) in VALID_MODES.iter().enumerate() {
+        let mode = mode_from_valid_index(index).unwrap();
+        assert_eq!(mode.as_gl_enum(), gl_enum);
+        assert_eq!(mode.valid_index(), index);
+    }
+    assert!(mode_from_valid_index(VALID_MODES.len()).is_none());
+}
+
+#[test]
+fn test_mode_gl_enum_is_not_discriminant() {
+    use gltf_json::mesh::{Mode, TRIANGLES};
+    use gltf_json::validation::Checked;
+
+    assert_eq!(Mode::Triangles.as_gl_enum(), TRIANGLES);
+    assert_ne!(Mode::Triangles.as_gl_enum(), Mode::Triangles as u32);
+    assert_eq!(serde_json::to_value(Mode::Triangles).unwrap(), json!(4));
+
+    let mode: Checked<Mode> = serde_json::from_value(json!(4)).unwrap();
+    assert_eq!(mode, Checked::Valid(Mode::Triangles));
+    let mode: Checked<Mode> = serde_json::from_value(json!((1u64 << 32) + 4)).unwrap();
+    assert_eq!(mode, Checked::Invalid);
+}
+
+#[cfg(not(feature = "lenient"))]
+#[test]
+fn test_single_primitive_object_is_rejected() {
+    let primitive = json!({ "attributes": { "POSITION": 0 } });
+    let result = serde_json::from_value::<Mesh>(json!({ "primitives": primitive }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_semantic_ordering() {
+    use gltf_json::mesh::Semantic::*;
+
+    assert!(Positions < Normals);
+    assert!(Normals < Tangents);
+    assert!(Tangents < Colors(0));
+    assert!(Colors(1) < TexCoords(0));
+    assert!(TexCoords(0) < TexCoords(1));
+    assert!(TexCoords(1) < Joints(0));
+    assert!(Joints(0) < Weights(0));
+
+    let mut shuffled = vec![
+        Weights(0),
+        TexCoords(1),
+        Positions,
+        Joints(0),
+        Colors(0),
+        Tangents,
+        TexCoords(0),
+        Normals,
+    ];
+    shuffled.sort();
+    assert_eq!(
+        shuffled,
+        [
+            Positions,
+            Normals,
+            Tangents,
+            Colors(0),
+            TexCoords(0),
+            TexCoords(1),
+            Joints(0),
+            Weights(0),
+        ]
+    );
+}
+
+#[cfg(not(feature = "lenient"))]
+#[test]
+fn test_stringified_attribute_index_rejected() {
+    let result = serde_json::from_value::<gltf_json::mesh::Primitive>(
+        json!({ "attributes": { "POSITION": "0" } }),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_remap_accessors() {
+    use gltf_json::mesh::{Primitive, Semantic};
+    use gltf_json::validation::Checked;
+    use std::collections::HashMap;
+
+    let mut primitive: Primitive = serde_json::from_value(json!({
+        "attributes": { "POSITION": 4, "NORMAL": 5, "TEXCOORD_0": 1 },
+        "indices": 6,
+        "targets": [{ "POSITION": 7, "NORMAL": 8 }],
+    }))
+    .unwrap();
+    let mapping: HashMap<usize, usize> = [(4, 0), (5, 1), (6, 2), (7, 3), (8, 4)]
+        .iter()
+        .copied()
+        .collect();
+    primitive.remap_accessors(&mapping);
+
+    let attribute = |semantic| primitive.attributes[&Checked::Valid(semantic)];
+    assert_eq!(attribute(Semantic::Positions), Index::new(0));
+    assert_eq!(attribute(Semantic::Normals), Index::new(1));
+    assert_eq!(attribute(Semantic::TexCoords(0)), Index::new(1));
+    assert_eq!(primitive.indices, Some(Index::new(2)));
+    let target = &primitive.targets.as_ref().unwrap()[0];
+    assert_eq!(target.positions, Some(Index::new(3)));
+    assert_eq!(target.normals, Some(Index::new(4)));
+    assert_eq!(target.tangents, None);
+}
+
+#[test]
+fn test_append_keeps_primitives_and_weights() {
+    use gltf_json::extras::ExtrasMerge;
+
+    let mut first = mesh(1);
+    let mut second = mesh(2);
+    second.weights = Some(vec![0.5]);
+    first.append(second, ExtrasMerge::First);
+    assert_eq!(first.primitives.len(), 3);
+    assert_eq!(first.weights, Some(vec![0.5]));
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_append_keeps_first_name() {
+    use gltf_json::extras::ExtrasMerge;
+
+    let mut first = mesh(1);
+    first.name = Some("first".into());
+    let mut second = mesh(1);
+    second.name = Some("second".into());
+    first.append(second, ExtrasMerge::First);
+    assert_eq!(first.name.as_deref(), Some("first"));
+
+    let mut unnamed = mesh(1);
+    unnamed.append(first, ExtrasMerge::First);
+    assert_eq!(unnamed.name.as_deref(), Some("first"));
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_append_merges_extras() {
+    use gltf_json::extras::ExtrasMerge;
+
+    let with_extras = |extras: serde_json::Value| {
+        let mut mesh = mesh(1);
+        mesh.extras = Some(serde_json::value::to_raw_value(&extras).unwrap());
+        mesh
+    };
+    let extras = |mesh: &Mesh| {
+        serde_json::from_str::<serde_json::Value>(mesh.extras.as_ref().unwrap().get()).unwrap()
+    };
+    let first = || with_extras(json!({ "a": 1, "b": 1 }));
+    let second = || with_extras(json!({ "b": 2, "c": 2 }));
+
+    let mut merged = first();
+    merged.append(second(), ExtrasMerge::Union);
+    assert_eq!(extras(&merged), json!({ "a": 1, "b": 1, "c": 2 }));
+
+    let mut merged = first();
+    merged.append(second(), ExtrasMerge::Second);
+    assert_eq!(extras(&merged), json!({ "b": 2, "c": 2 }));
+
+    let mut merged = mesh(1);
+    merged.append(second(), ExtrasMerge::First);
+    assert_eq!(extras(&merged), json!({ "b": 2, "c": 2 }));
+}
+
+#[test]
+fn test_compact_attribute_sets() {
+    use gltf_json::mesh::{Primitive, Semantic};
+    use gltf_json::validation::Checked;
+
+    let mut primitive: Primitive = serde_json::from_value(json!({
+        "attributes": {
+            "POSITION": 0,
+            "TEXCOORD_0": 1,
+            "TEXCOORD_2": 2,
+            "TEXCOORD_5": 3,
+            "COLOR_1": 4,
+        },
+    }))
+    .unwrap();
+    assert!(primitive.compact_attribute_sets());
+
+    let mut attributes: Vec<(String, usize)> = primitive
+        .attributes
+        .iter()
+        .map(|(semantic, index)| (semantic.to_string(), index.value()))
+        .collect();
+    attributes.sort();
+    assert_eq!(
+        attributes,
+        [
+            ("COLOR_0".to_string(), 4),
+            ("POSITION".to_string(), 0),
+            ("TEXCOORD_0".to_string(), 1),
+            ("TEXCOORD_1".to_string(), 2),
+            ("TEXCOORD_2".to_string(), 3),
+        ]
+    );
+    assert!(primitive
+        .attributes
+        .contains_key(&Checked::Valid(Semantic::TexCoords(2))));
+    assert!(!primitive.compact_attribute_sets());
+}
+
+#[test]
+fn test_attributes_with_missing_accessors() {
+    use gltf_json::mesh::Semantic;
+
+    let root: gltf_json::Root = serde_json::from_value(json!({
+        "asset": { "version": "2.0" },
+        "accessors": [
+            { "componentType": 5126, "count": 3, "type": "VEC3" },
+            { "componentType": 5126, "count": 3, "type": "VEC5" },
+        ],
+        "meshes": [{
+            "primitives": [{
+                "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 7, "_CUSTOM": 9 },
+            }],
+        }],
+    }))
+    .unwrap();
+    let primitive = &root.meshes[0].primitives[0];
+    let missing = primitive.attributes_with_missing_accessors(&root);
+    #[cfg(not(feature = "extras"))]
+    assert_eq!(missing, [Semantic::Normals, Semantic::TexCoords(0)]);
+    #[cfg(feature = "extras")]
+    assert_eq!(
+        missing,
+        [
+            Semantic::Normals,
+            Semantic::TexCoords(0),
+            Semantic::Extras("CUSTOM".into()),
+        ]
+    );
+}
+
+#[cfg(feature = "extension_modes")]
+#[test]
+fn test_extension_mode_round_trip() {
+    use gltf_json::mesh::{Mode, Primitive};
+    use gltf_json::validation::{Checked, Validate};
+
+    let value = json!({ "attributes": { "POSITION": 0 }, "mode": 42 });
+    let primitive: Primitive = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(primitive.mode, Checked::Invalid);
+    assert_eq!(primitive.extension_mode(), Some(42));
+    assert_eq!(serde_json::to_value(&primitive).unwrap(), value);
+
+    let primitive: Primitive =
+        serde_json::from_value(json!({ "attributes": { "POSITION": 0 }, "mode": 1 })).unwrap();
+    assert_eq!(primitive.mode, Checked::Valid(Mode::Lines));
+    assert_eq!(primitive.extension_mode(), None);
+
+    let mut root: gltf_json::Root = serde_json::from_value(json!({
+        "asset": { "version": "2.0" },
+        "buffers": [{ "byteLength": 36 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 36 }],
+        "accessors": [{
+            "bufferView": 0,
+            "componentType": 5126,
+            "count": 3,
+            "type": "VEC3",
+            "min": [0.0, 0.0, 0.0],
+            "max": [1.0, 1.0, 0.0],
+        }],
+        "meshes": [{ "primitives": [value] }],
+    }))
+    .unwrap();
+    let errors = |root: &gltf_json::Root| {
+        let mut errors = Vec::new();
+        root.validate(root, gltf_json::Path::new, &mut |path, error| {
+            errors.push((path().as_str().to_owned(), error))
+        });
+        errors
+    };
+    assert_eq!(
+        errors(&root),
+        [(
+            "meshes[0].primitives[0].mode".to_owned(),
+            gltf_json::validation::Error::Invalid
+        )]
+    );
+    root.extensions_used
+        .push("VENDOR_primitive_modes".to_owned());
+    assert!(errors(&root).is_empty());
+}
@@ -44,3 +44,766 @@ fn test_non_sparse_accessor_without_buffer_view_validate() {
         [(Path("accessors[0].bufferView".into()), Error::Missing)]
     );
 }
+
+/// Returns an asset with a mesh whose primitive has a valid `POSITION`
+/// accessor, for tests to patch in the fields they exercise.
+fn base_root() -> serde_json::Value {
+    serde_json::json!({
+        "asset": { "version": "2.0" },
+        "buffers": [{ "byteLength": 72 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 72 }],
+        "accessors": [{
+            "bufferView": 0,
+            "componentType": 5126,
+            "count": 3,
+            "type": "VEC3",
+            "min": [0.0, 0.0, 0.0],
+            "max": [1.0, 1.0, 0.0],
+        }],
+        "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+    })
+}
+
+/// Appends the items of the JSON array `items` to the JSON array `array`.
+fn push(array: &mut serde_json::Value, items: serde_json::Value) {
+    let items = match items {
+        serde_json::Value::Array(items) => items,
+        _ => panic!("expected an array"),
+    };
+    array.as_array_mut().unwrap().extend(items);
+}
+
+fn validate(json: serde_json::Value) -> Vec<(Path, Error)> {
+    let root: gltf_json::Root = serde_json::from_value(json).unwrap();
+    let mut errs = vec![];
+    root.validate(&root, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    errs
+}
+
+#[test]
+fn test_morph_target_displacement_type_validate() {
+    let mut json = base_root();
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "componentType": 5123, "count": 3, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["targets"] =
+        serde_json::json!([{ "POSITION": 2 }, { "POSITION": 1 }]);
+    let errs = validate(json);
+    assert_eq!(
+        errs,
+        [(
+            Path("meshes[0].primitives[0].targets[1][\"POSITION\"]".into()),
+            Error::Invalid
+        )]
+    );
+}
+
+#[test]
+fn test_quantized_morph_target_displacement_validate() {
+    let mut json = base_root();
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "componentType": 5122, "count": 3, "type": "VEC3" },
+            {
+                "bufferView": 0,
+                "componentType": 5120,
+                "count": 3,
+                "type": "VEC3",
+                "normalized": true,
+            },
+            { "bufferView": 0, "componentType": 5120, "count": 3, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5121, "count": 3, "type": "VEC3" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["attributes"]["NORMAL"] = serde_json::json!(0);
+    json["meshes"][0]["primitives"][0]["targets"] = serde_json::json!([
+        { "POSITION": 1, "NORMAL": 2 },
+        { "NORMAL": 3 },
+        { "POSITION": 4 },
+    ]);
+    let errors = |json: serde_json::Value| {
+        validate(json)
+            .into_iter()
+            .map(|(path, err)| (path.as_str().to_owned(), err))
+            .collect::<Vec<_>>()
+    };
+    let target = |index: usize, semantic: &str| {
+        (
+            format!(
+                "meshes[0].primitives[0].targets[{}][\"{}\"]",
+                index, semantic
+            ),
+            Error::Invalid,
+        )
+    };
+    let mut expected = vec![
+        target(0, "POSITION"),
+        target(0, "NORMAL"),
+        target(1, "NORMAL"),
+        target(2, "POSITION"),
+    ];
+    let mut errs = errors(json.clone());
+    errs.sort();
+    expected.sort();
+    assert_eq!(errs, expected);
+
+    // Quantized displacements may be signed, and must be normalized for
+    // normals. Unsigned types remain invalid.
+    json["extensionsUsed"] = serde_json::json!(["KHR_mesh_quantization"]);
+    let mut expected = vec![target(1, "NORMAL"), target(2, "POSITION")];
+    let mut errs = errors(json);
+    errs.sort();
+    expected.sort();
+    assert_eq!(errs, expected);
+}
+
+#[test]
+fn test_renderer_strict_profile_validate() {
+    use gltf_json::validation::Profile;
+
+    let root: gltf_json::Root = serde_json::from_value(serde_json::json!({
+        "asset": { "version": "2.0" },
+        "meshes": [{
+            "primitives": [
+                { "attributes": {}, "mode": 1 },
+                { "attributes": {}, "mode": 2 },
+                { "attributes": {}, "mode": 2, "indices": 0 },
+            ],
+        }],
+    }))
+    .unwrap();
+    let mut errs = vec![];
+    root.validate_profile(Profile::RendererStrict, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs.iter()
+            .map(|(path, err)| (path.as_str(), *err))
+            .collect::<Vec<_>>(),
+        [("meshes[0].primitives[1].indices", Error::Missing)]
+    );
+}
+
+#[test]
+fn test_weights_validate() {
+    use gltf_json::validation::{Profile, Validate};
+
+    let mut root: gltf_json::Root = serde_json::from_value(serde_json::json!({
+        "asset": { "version": "2.0" },
+        "meshes": [{ "primitives": [], "weights": [0.5, 0.5] }],
+        "nodes": [{ "weights": [-0.25, 1.0] }],
+    }))
+    .unwrap();
+    root.meshes[0].weights.as_mut().unwrap()[1] = f32::NAN;
+
+    let mut errs = vec![];
+    root.validate(&root, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs.iter()
+            .map(|(path, err)| (path.as_str(), *err))
+            .collect::<Vec<_>>(),
+        [("meshes[0].weights[1]", Error::Invalid)]
+    );
+
+    let mut errs = vec![];
+    root.validate_profile(Profile::RendererStrict, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs.iter()
+            .map(|(path, err)| (path.as_str(), *err))
+            .collect::<Vec<_>>(),
+        [("nodes[0].weights[0]", Error::Invalid)]
+    );
+}
+
+#[test]
+fn test_overflowing_weights_validate() {
+    use gltf_json::validation::Validate;
+
+    let root: gltf_json::Root = serde_json::from_str(
+        r#"{
+            "asset": { "version": "2.0" },
+            "meshes": [{ "primitives": [], "weights": [1e39] }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(root.meshes[0].weights, Some(vec![f32::INFINITY]));
+
+    let mut errs = vec![];
+    let path = || gltf_json::Path::new().field("assets").index(0);
+    root.validate(&root, path, &mut |path, err| errs.push((path(), err)));
+    assert_eq!(
+        errs.iter()
+            .map(|(path, err)| (path.as_str(), *err))
+            .collect::<Vec<_>>(),
+        [("assets[0].meshes[0].weights[0]", Error::Invalid)]
+    );
+}
+
+#[test]
+fn test_webgl1_profile() {
+    use gltf_json::validation::Profile;
+
+    let attributes: serde_json::Map<_, _> = (0..9)
+        .map(|set| (format!("TEXCOORD_{}", set), serde_json::json!(0)))
+        .collect();
+    let root: gltf_json::Root = serde_json::from_value(serde_json::json!({
+        "asset": { "version": "2.0" },
+        "accessors": [
+            { "componentType": 5125, "count": 3, "type": "SCALAR" },
+            { "componentType": 5123, "count": 3, "type": "SCALAR" },
+        ],
+        "meshes": [{
+            "primitives": [
+                { "attributes": {}, "indices": 0 },
+                { "attributes": {}, "indices": 1 },
+                { "attributes": attributes },
+            ],
+        }],
+    }))
+    .unwrap();
+    let mut errs = vec![];
+    root.validate_profile(Profile::WebGl1, &mut |path, err| errs.push((path(), err)));
+    assert_eq!(
+        errs.iter()
+            .map(|(path, err)| (path.as_str(), *err))
+            .collect::<Vec<_>>(),
+        [
+            ("meshes[0].primitives[0].indices", Error::Invalid),
+            ("meshes[0].primitives[2].attributes", Error::Invalid),
+        ]
+    );
+}
+
+#[test]
+fn test_joints_and_weights_type_validate() {
+    let mut json = base_root();
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "componentType": 5123, "count": 3, "type": "VEC4" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["attributes"] =
+        serde_json::json!({ "POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 2 });
+    let errs = validate(json);
+    assert_eq!(
+        errs,
+        [(
+            Path("meshes[0].primitives[0].attributes[\"WEIGHTS_0\"]".into()),
+            Error::Invalid
+        )]
+    );
+}
+
+#[test]
+fn test_attribute_layouts_validate() {
+    let mut json = base_root();
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "componentType": 5121, "count": 3, "type": "VEC2" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR" },
+            {
+                "bufferView": 0,
+                "componentType": 5126,
+                "count": 3,
+                "type": "VEC4",
+                "normalized": true,
+            },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0] = serde_json::json!({
+        "attributes": {
+            "POSITION": 0,
+            "TEXCOORD_0": 1,
+            "COLOR_0": 2,
+            "COLOR_1": 4,
+        },
+        "indices": 3,
+    });
+    let mut errs = validate(json.clone());
+    errs.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+    assert_eq!(
+        errs.iter()
+            .map(|(path, err)| (path.as_str(), *err))
+            .collect::<Vec<_>>(),
+        [
+            ("accessors[4].normalized", Error::Invalid),
+            (
+                "meshes[0].primitives[0].attributes[\"COLOR_0\"]",
+                Error::Invalid
+            ),
+            (
+                "meshes[0].primitives[0].attributes[\"COLOR_1\"]",
+                Error::Invalid
+            ),
+            (
+                "meshes[0].primitives[0].attributes[\"TEXCOORD_0\"]",
+                Error::Invalid
+            ),
+            ("meshes[0].primitives[0].indices", Error::Invalid),
+        ]
+    );
+
+    // Quantized texture co-ordinates need not be normalized.
+    json["extensionsUsed"] = serde_json::json!(["KHR_mesh_quantization"]);
+    json["meshes"][0]["primitives"][0] =
+        serde_json::json!({ "attributes": { "POSITION": 0, "TEXCOORD_0": 1 } });
+    assert_eq!(
+        validate(json),
+        [(Path("accessors[4].normalized".into()), Error::Invalid)]
+    );
+}
+
+#[test]
+fn test_f32_joints_validate() {
+    let mut json = base_root();
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC4" },
+            { "bufferView": 0, "componentType": 5121, "count": 3, "type": "VEC4" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC4" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["attributes"] = serde_json::json!({
+        "POSITION": 0,
+        "JOINTS_0": 1,
+        "JOINTS_1": 2,
+        "WEIGHTS_0": 3,
+        "WEIGHTS_1": 3,
+    });
+    let errs = validate(json);
+    assert_eq!(
+        errs,
+        [(
+            Path("meshes[0].primitives[0].attributes[\"JOINTS_0\"]".into()),
+            Error::Invalid
+        )]
+    );
+}
+
+#[test]
+fn test_buffer_view_target_conflict_validate() {
+    let mut json = base_root();
+    json["bufferViews"] = serde_json::json!([
+        { "buffer": 0, "byteLength": 36, "target": 34962 },
+        { "buffer": 0, "byteOffset": 36, "byteLength": 6, "target": 34962 },
+    ]);
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["indices"] = serde_json::json!(1);
+    let errs = validate(json);
+    assert_eq!(
+        errs,
+        [(
+            Path("meshes[0].primitives[0].indices".into()),
+            Error::Invalid
+        )]
+    );
+}
+
+#[test]
+fn test_tex_coords_type_validate() {
+    let mut json = base_root();
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["attributes"] =
+        serde_json::json!({ "POSITION": 0, "TEXCOORD_0": 1, "TEXCOORD_1": 2 });
+    let errs = validate(json);
+    assert_eq!(
+        errs,
+        [(
+            Path("meshes[0].primitives[0].attributes[\"TEXCOORD_0\"]".into()),
+            Error::Invalid
+        )]
+    );
+}
+
+#[test]
+fn test_primitive_material_out_of_range_validate() {
+    let mut json = base_root();
+    json["materials"] = serde_json::json!([{}, {}]);
+    json["meshes"][0]["primitives"] = serde_json::json!([
+        { "attributes": { "POSITION": 0 }, "material": 1 },
+        { "attributes": { "POSITION": 0 }, "material": 10 },
+    ]);
+    let errs = validate(json);
+    assert_eq!(
+        errs,
+        [(
+            Path("meshes[0].primitives[1].material".into()),
+            Error::IndexOutOfBounds
+        )]
+    );
+}
+
+#[test]
+fn test_validate_report_severities() {
+    use gltf_json::validation::{Severities, Severity};
+
+    let json = import_json("tests/minimal_accessor_invalid.gltf");
+    let report = json.validate_report(&Severities::new());
+    assert_eq!(report.errors().count(), 2);
+    assert_eq!(report.warnings().count(), 0);
+
+    let severities = Severities::new().set(Error::Missing, ".min", Severity::Warning);
+    let report = json.validate_report(&severities);
+    let min = Path("meshes[0].primitives[0].attributes[\"POSITION\"].min".into());
+    let max = Path("meshes[0].primitives[0].attributes[\"POSITION\"].max".into());
+    let warnings: Vec<_> = report.warnings().map(|issue| &issue.path).collect();
+    assert_eq!(warnings, [&min]);
+    let errors: Vec<_> = report
+        .errors()
+        .map(|issue| (&issue.path, issue.error))
+        .collect();
+    assert_eq!(errors, [(&max, Error::Invalid)]);
+    assert!(report.has_errors());
+
+    let severities = severities.set(Error::Invalid, ".max", Severity::Warning);
+    assert!(!json.validate_report(&severities).has_errors());
+}
+
+#[test]
+fn test_attribute_sets_contiguous_validate() {
+    let mut json = base_root();
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["attributes"] =
+        serde_json::json!({ "POSITION": 0, "TEXCOORD_0": 1, "TEXCOORD_2": 1 });
+    let errs = validate(json);
+    assert_eq!(
+        errs,
+        [(
+            Path("meshes[0].primitives[0].attributes[\"TEXCOORD_2\"]".into()),
+            Error::Invalid
+        )]
+    );
+}
+
+#[test]
+fn test_buffer_view_target_advisory() {
+    use gltf_json::validation::{Severities, Severity};
+
+    let mut json = base_root();
+    json["buffers"][0]["byteLength"] = serde_json::json!(80);
+    json["bufferViews"] = serde_json::json!([
+        { "buffer": 0, "byteLength": 36, "target": 34962 },
+        { "buffer": 0, "byteOffset": 36, "byteLength": 36, "target": 34963 },
+        { "buffer": 0, "byteOffset": 72, "byteLength": 6 },
+    ]);
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3" },
+            { "bufferView": 2, "componentType": 5123, "count": 3, "type": "SCALAR" },
+        ]),
+    );
+    json["meshes"][0]["primitives"] = serde_json::json!([
+        { "attributes": { "POSITION": 0, "NORMAL": 1 }, "indices": 2 },
+        { "attributes": { "POSITION": 0 }, "indices": 2 },
+    ]);
+    let root: gltf_json::Root = serde_json::from_value(json).unwrap();
+
+    let report = root.validate_report(&Severities::new());
+    let errors: Vec<_> = report.errors().map(|issue| issue.path.as_str()).collect();
+    assert_eq!(errors, ["meshes[0].primitives[0].attributes[\"NORMAL\"]"]);
+    let mut warnings: Vec<_> = report
+        .warnings()
+        .map(|issue| (issue.path.as_str(), issue.error))
+        .collect();
+    warnings.sort();
+    assert_eq!(
+        warnings,
+        [
+            ("bufferViews[1].target", Error::Invalid),
+            ("bufferViews[2].target", Error::Missing),
+        ]
+    );
+
+    let severities = Severities::new().set(Error::Missing, ".target", Severity::Error);
+    assert_eq!(root.validate_report(&severities).errors().count(), 2);
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+#[test]
+fn test_extensions_used_validate() {
+    let mut json = base_root();
+    json["materials"] = serde_json::json!([{}]);
+    json["meshes"][0]["primitives"][0]["extensions"] = serde_json::json!({
+        "KHR_materials_variants": {
+            "mappings": [{ "material": 0, "variants": [0] }],
+        },
+    });
+    json["extensions"] = serde_json::json!({
+        "KHR_materials_variants": { "variants": [{ "name": "default" }] },
+    });
+    assert_eq!(
+        validate(json.clone()),
+        [(
+            Path("extensionsUsed[\"KHR_materials_variants\"]".into()),
+            Error::Missing
+        )]
+    );
+
+    json["extensionsUsed"] = serde_json::json!(["KHR_materials_variants"]);
+    assert!(validate(json).is_empty());
+}
+
+#[test]
+fn test_unsupported_extensions_used_validate() {
+    let mut json = base_root();
+    json["meshes"][0]["primitives"][0]["extensions"] = serde_json::json!({
+        "KHR_draco_mesh_compression": {
+            "bufferView": 0,
+            "attributes": { "POSITION": 0 },
+        },
+    });
+    assert_eq!(
+        validate(json.clone()),
+        [(
+            Path("extensionsUsed[\"KHR_draco_mesh_compression\"]".into()),
+            Error::Missing
+        )]
+    );
+
+    json["extensionsUsed"] = serde_json::json!(["KHR_draco_mesh_compression"]);
+    assert!(validate(json).is_empty());
+}
+
+#[test]
+fn test_attribute_counts_validate() {
+    let mut json = base_root();
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["attributes"] =
+        serde_json::json!({ "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2 });
+    let errs = validate(json);
+    assert_eq!(
+        errs,
+        [(
+            Path("meshes[0].primitives[0].attributes[\"NORMAL\"]".into()),
+            Error::Invalid
+        )]
+    );
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_target_names_advisory() {
+    use gltf_json::validation::Severities;
+
+    let mut json = base_root();
+    json["bufferViews"][0]["target"] = serde_json::json!(34962);
+    json["meshes"][0]["primitives"][0]["targets"] =
+        serde_json::json!([{ "POSITION": 0 }, { "POSITION": 0 }]);
+    json["meshes"][0]["extras"] = serde_json::json!({ "targetNames": ["smile", "blink"] });
+    let root: gltf_json::Root = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(
+        root.meshes[0].target_names(),
+        Some(vec!["smile".to_owned(), "blink".to_owned()])
+    );
+    assert!(root.validate_report(&Severities::new()).issues.is_empty());
+
+    json["meshes"][0]["extras"] = serde_json::json!({ "targetNames": ["smile"] });
+    let root: gltf_json::Root = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(
+        root.meshes[0].target_names(),
+        Some(vec!["smile".to_owned()])
+    );
+    let report = root.validate_report(&Severities::new());
+    let warnings: Vec<_> = report
+        .warnings()
+        .map(|issue| (issue.path.as_str(), issue.error))
+        .collect();
+    assert_eq!(warnings, [("meshes[0].extras.targetNames", Error::Invalid)]);
+    assert!(!report.has_errors());
+
+    json["meshes"][0]["extras"] = serde_json::json!({ "other": 1 });
+    let root: gltf_json::Root = serde_json::from_value(json).unwrap();
+    assert_eq!(root.meshes[0].target_names(), None);
+}
+
+#[test]
+fn test_required_attributes_validate() {
+    let mut json = base_root();
+    let root: gltf_json::Root = serde_json::from_value(json.clone()).unwrap();
+    assert!(root.meshes[0].primitives[0].has_required_attributes());
+    assert!(validate(json.clone()).is_empty());
+
+    json["meshes"][0]["primitives"][0]["attributes"] = serde_json::json!({ "NORMAL": 0 });
+    let root: gltf_json::Root = serde_json::from_value(json.clone()).unwrap();
+    assert!(!root.meshes[0].primitives[0].has_required_attributes());
+    assert_eq!(
+        validate(json),
+        [(
+            Path("meshes[0].primitives[0].attributes[\"POSITION\"]".into()),
+            Error::Missing
+        )]
+    );
+}
+
+#[test]
+fn test_sparse_attribute_stride_validate() {
+    use gltf_json::validation::Severities;
+
+    let mut json = base_root();
+    json["buffers"][0]["byteLength"] = serde_json::json!(40);
+    json["bufferViews"] = serde_json::json!([
+        { "buffer": 0, "byteLength": 24, "byteStride": 12, "target": 34962 },
+        { "buffer": 0, "byteOffset": 24, "byteLength": 2 },
+        { "buffer": 0, "byteOffset": 28, "byteLength": 12, "byteStride": 12 },
+    ]);
+    json["accessors"][0]["count"] = serde_json::json!(2);
+    json["accessors"][0]["sparse"] = serde_json::json!({
+        "count": 1,
+        "indices": { "bufferView": 1, "componentType": 5123 },
+        "values": { "bufferView": 2 },
+    });
+    let root: gltf_json::Root = serde_json::from_value(json).unwrap();
+
+    let report = root.validate_report(&Severities::new());
+    let errors: Vec<_> = report
+        .errors()
+        .map(|issue| (issue.path.as_str(), issue.error))
+        .collect();
+    assert_eq!(
+        errors,
+        [("accessors[0].sparse.values.bufferView", Error::Invalid)]
+    );
+    let warnings: Vec<_> = report
+        .warnings()
+        .map(|issue| (issue.path.as_str(), issue.error))
+        .collect();
+    assert_eq!(warnings, [("accessors[0]", Error::Invalid)]);
+}
+
+#[test]
+fn test_morph_target_bounds_advisory() {
+    use gltf_json::validation::Severities;
+
+    let mut json = base_root();
+    json["bufferViews"][0]["target"] = serde_json::json!(34962);
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["targets"] =
+        serde_json::json!([{ "POSITION": 0 }, { "POSITION": 1 }]);
+    let root: gltf_json::Root = serde_json::from_value(json).unwrap();
+
+    let report = root.validate_report(&Severities::new());
+    assert!(!report.has_errors());
+    let warnings: Vec<_> = report
+        .warnings()
+        .map(|issue| (issue.path.as_str(), issue.error))
+        .collect();
+    assert_eq!(
+        warnings,
+        [
+            ("accessors[1].min", Error::Missing),
+            ("accessors[1].max", Error::Missing),
+        ]
+    );
+}
+
+#[test]
+fn test_unexpected_morph_target_attribute_advisory() {
+    use gltf_json::validation::Severities;
+
+    let mut json = base_root();
+    json["bufferViews"][0]["target"] = serde_json::json!(34962);
+    json["meshes"][0]["primitives"][0]["targets"] =
+        serde_json::json!([{ "POSITION": 0 }, { "POSITION": 0, "COLOR_0": 0 }]);
+    let root: gltf_json::Root = serde_json::from_value(json).unwrap();
+
+    let report = root.validate_report(&Severities::new());
+    assert!(!report.has_errors());
+    let warnings: Vec<_> = report
+        .warnings()
+        .map(|issue| (issue.path.as_str(), issue.error))
+        .collect();
+    assert_eq!(
+        warnings,
+        [(
+            "meshes[0].primitives[0].targets[1][\"COLOR_0\"]",
+            Error::Invalid
+        )]
+    );
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_empty_custom_attribute_name_validate() {
+    let mut json = base_root();
+    json["meshes"][0]["primitives"][0]["attributes"] =
+        serde_json::json!({ "POSITION": 0, "_": 0, "_TEMPERATURE": 0 });
+    let errs = validate(json);
+    assert_eq!(
+        errs,
+        [(
+            Path("meshes[0].primitives[0].attributes[\"_\"]".into()),
+            Error::Invalid
+        )]
+    );
+}
+
+#[test]
+fn test_vec3_tangents_validate() {
+    use gltf_json::validation::Severities;
+
+    let mut json = base_root();
+    push(
+        &mut json["accessors"],
+        serde_json::json!([
+            { "bufferView": 0, "byteOffset": 36, "componentType": 5126, "count": 3, "type": "VEC3" },
+        ]),
+    );
+    json["meshes"][0]["primitives"][0]["attributes"] =
+        serde_json::json!({ "POSITION": 0, "TANGENT": 1 });
+    let root: gltf_json::Root = serde_json::from_value(json).unwrap();
+
+    let report = root.validate_report(&Severities::new());
+    let errors: Vec<_> = report.errors().map(|issue| issue.path.as_str()).collect();
+    let warnings: Vec<_> = report.warnings().map(|issue| issue.path.as_str()).collect();
+    let tangent = "meshes[0].primitives[0].attributes[\"TANGENT\"]";
+    if cfg!(feature = "lenient") {
+        assert!(errors.is_empty());
+        assert!(warnings.contains(&tangent));
+    } else {
+        assert_eq!(errors, [tangent]);
+    }
+}
@@ -106,7 +106,7 @@ impl<'a> Accessor<'a> {
 
     /// Returns the size of each component that this accessor describes.
     pub fn size(&self) -> usize {
-        self.data_type().size() * self.dimensions().multiplicity()
+        self.json.element_byte_size().unwrap()
     }
 
     /// Returns the buffer view this accessor reads from.
@@ -166,6 +166,21 @@ impl<'a> Accessor<'a> {
         self.json.normalized
     }
 
+    /// Returns the buffer view and byte offset of the double precision copy of
+    /// the accessor data defined by the custom `EXT_accessor_double` extension.
+    #[cfg(feature = "EXT_accessor_double")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_accessor_double")))]
+    pub fn double_precision(&self) -> Option<(buffer::View<'a>, usize)> {
+        let double = self
+            .json
+            .extensions
+            .as_ref()?
+            .ext_accessor_double
+            .as_ref()?;
+        let view = self.document.views().nth(double.buffer_view.value())?;
+        Some((view, double.byte_offset as usize))
+    }
+
     /// Returns sparse storage of attributes that deviate from their initialization
     /// value.
     pub fn sparse(&self) -> Option<sparse::Sparse<'a>> {
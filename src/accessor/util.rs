@@ -13,6 +13,169 @@ fn buffer_view_slice<'a, 's>(
     get_buffer_data(view.buffer()).and_then(|slice| slice.get(start..end))
 }
 
+/// Returns the raw bytes of an accessor if they are tightly packed in memory.
+///
+/// This is the case when the accessor is not sparse and its buffer view has
+/// no byte stride, or a byte stride equal to the size of one element. The
+/// returned slice contains exactly `accessor.count() * accessor.size()` bytes
+/// in glTF (little-endian) byte order.
+pub fn packed_slice<'a, 's, F>(
+    accessor: &accessor::Accessor<'a>,
+    get_buffer_data: F,
+) -> Option<&'s [u8]>
+where
+    F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    if accessor.sparse().is_some() {
+        return None;
+    }
+    let view = accessor.view()?;
+    let size = accessor.size();
+    if view.stride().unwrap_or(size) != size {
+        return None;
+    }
+    let start = accessor.offset();
+    let end = start + size * accessor.count();
+    buffer_view_slice(view, &get_buffer_data).and_then(|slice| slice.get(start..end))
+}
+
+/// Visits the double precision copy of the accessor data defined by the
+/// custom `EXT_accessor_double` extension.
+///
+/// Returns `None` if the accessor has no such copy or its data is
+/// unavailable.
+#[cfg(feature = "EXT_accessor_double")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_accessor_double")))]
+pub fn double_precision_iter<'a, 's, T, F>(
+    accessor: &accessor::Accessor<'a>,
+    get_buffer_data: F,
+) -> Option<ItemIter<'s, T>>
+where
+    T: Item,
+    F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let (view, start) = accessor.double_precision()?;
+    let stride = view.stride().unwrap_or_else(T::size);
+    let end = match accessor.count() {
+        0 => start,
+        count => start + stride * (count - 1) + T::size(),
+    };
+    let slice = buffer_view_slice(view, &get_buffer_data)?.get(start..end)?;
+    Some(ItemIter::new(slice, stride))
+}
+
+/// Reads the indices of the elements substituted by sparse storage.
+pub fn sparse_indices<'a, 's, F>(
+    sparse: &accessor::sparse::Sparse<'a>,
+    get_buffer_data: F,
+) -> Option<Vec<u32>>
+where
+    F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let indices = sparse.indices();
+    let index_size = indices.index_type().size();
+    let data =
+        buffer_view_slice(indices.view(), &get_buffer_data)?.get(indices.offset() as usize..)?;
+    (0..sparse.count() as usize)
+        .map(|i| {
+            let index = data.get(i * index_size..(i + 1) * index_size)?;
+            Some(match indices.index_type() {
+                accessor::sparse::IndexType::U8 => u32::from(index[0]),
+                accessor::sparse::IndexType::U16 => u32::from(LE::read_u16(index)),
+                accessor::sparse::IndexType::U32 => LE::read_u32(index),
+            })
+        })
+        .collect()
+}
+
+/// Returns the bytes of each element of an accessor, packed contiguously.
+///
+/// Any byte stride is removed and sparse substitutions are applied, so the
+/// result contains exactly `accessor.count() * accessor.size()` bytes in glTF
+/// (little-endian) byte order. Sparse accessors without a buffer view are
+/// initialized with zeros.
+pub fn resolved_bytes<'a, 's, F>(
+    accessor: &accessor::Accessor<'a>,
+    get_buffer_data: F,
+) -> Option<Vec<u8>>
+where
+    F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let size = accessor.size();
+    let count = accessor.count();
+    let mut bytes = match accessor.view() {
+        Some(view) => {
+            let stride = view.stride().unwrap_or(size);
+            let data = buffer_view_slice(view, &get_buffer_data)?.get(accessor.offset()..)?;
+            let mut bytes = Vec::with_capacity(size * count);
+            for i in 0..count {
+                bytes.extend_from_slice(data.get(i * stride..i * stride + size)?);
+            }
+            bytes
+        }
+        None => vec![0; size * count],
+    };
+
+    if let Some(sparse) = accessor.sparse() {
+        let indices = sparse_indices(&sparse, &get_buffer_data)?;
+        let values = sparse.values();
+        let value_data =
+            buffer_view_slice(values.view(), &get_buffer_data)?.get(values.offset() as usize..)?;
+        for (i, index) in indices.into_iter().enumerate() {
+            let index = index as usize;
+            let value = value_data.get(i * size..(i + 1) * size)?;
+            bytes
+                .get_mut(index * size..(index + 1) * size)?
+                .copy_from_slice(value);
+        }
+    }
+
+    Some(bytes)
+}
+
+/// The minimum number of elements decoded by one task of [`read_par`].
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_LEN: usize = 4096;
+
+/// Reads every element of an accessor, decoding chunks of elements across
+/// the threads of the global rayon thread pool.
+///
+/// The result is identical to collecting an [`Iter`] over the same accessor.
+/// Sparse accessors and accessors without a buffer view are read serially.
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn read_par<'a, 's, T, F>(
+    accessor: accessor::Accessor<'a>,
+    get_buffer_data: F,
+) -> Option<Vec<T>>
+where
+    T: Item + Send,
+    F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    use rayon::prelude::*;
+
+    let view = match accessor.view() {
+        Some(view) if accessor.sparse().is_none() => view,
+        _ => return Iter::new(accessor, get_buffer_data).map(Iterator::collect),
+    };
+    let size = T::size();
+    let stride = view.stride().unwrap_or(size);
+    let count = accessor.count();
+    if count == 0 {
+        return Some(Vec::new());
+    }
+    let start = accessor.offset();
+    let end = start + stride * (count - 1) + size;
+    let data = buffer_view_slice(view, &get_buffer_data).and_then(|slice| slice.get(start..end))?;
+    Some(
+        (0..count)
+            .into_par_iter()
+            .with_min_len(PAR_CHUNK_LEN)
+            .map(|i| T::from_slice(&data[i * stride..i * stride + size]))
+            .collect(),
+    )
+}
+
 /// General iterator for an accessor.
 #[derive(Clone, Debug)]
 pub enum Iter<'a, T: Item> {
@@ -102,22 +265,42 @@ pub struct SparseIter<'a, T: Item> {
 
     /// Iterator counter.
     counter: u32,
+
+    /// Number of elements in the accessor.
+    count: u32,
 }
 
 impl<'a, T: Item> SparseIter<'a, T> {
     /// Constructor.
     ///
     /// Here `base` is allowed to be `None` when the base buffer view is not explicitly specified.
+    /// In that case the iterator yields zero values without end; prefer
+    /// constructing the iterator through [`Iter::new`], which knows the element count.
     pub fn new(
         base: Option<ItemIter<'a, T>>,
         indices: SparseIndicesIter<'a>,
         values: ItemIter<'a, T>,
+    ) -> Self {
+        let count = base
+            .as_ref()
+            .map(|base| base.len() as u32)
+            .unwrap_or(u32::MAX);
+        Self::with_count(base, indices, values, count)
+    }
+
+    /// Constructor for an accessor of `count` elements.
+    pub(crate) fn with_count(
+        base: Option<ItemIter<'a, T>>,
+        indices: SparseIndicesIter<'a>,
+        values: ItemIter<'a, T>,
+        count: u32,
     ) -> Self {
         SparseIter {
             base,
             indices: indices.peekable(),
             values,
             counter: 0,
+            count,
         }
     }
 }
@@ -125,6 +308,9 @@ impl<'a, T: Item> SparseIter<'a, T> {
 impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.count {
+            return None;
+        }
         let mut next_value = self
             .base
             .as_mut()
@@ -145,7 +331,7 @@ impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.values.len() - self.counter as usize;
+        let hint = (self.count - self.counter) as usize;
         (hint, Some(hint))
     }
 }
@@ -156,10 +342,29 @@ impl<'a, T: Item> ExactSizeIterator for SparseIter<'a, T> {}
 ///
 /// [`Accessor`]: struct.Accessor.html
 pub trait Item {
-    /// Create an object of this type from a byte slice.
+    /// Create an object of this type from a little-endian byte slice,
+    /// independent of the byte order of the host.
     fn from_slice(slice: &[u8]) -> Self;
     /// Create an object of this type that represents a zero value.
     fn zero() -> Self;
+    /// Returns the number of bytes this type occupies in a buffer.
+    fn size() -> usize
+    where
+        Self: Sized,
+    {
+        mem::size_of::<Self>()
+    }
+    /// Returns the number of bytes this type occupies as a column of a
+    /// matrix.
+    ///
+    /// Matrix columns are aligned to 4-byte boundaries, so this may include
+    /// padding. Scalars are never padded.
+    fn column_size() -> usize
+    where
+        Self: Sized,
+    {
+        Self::size()
+    }
 }
 
 /// Visits the items in an [`Accessor`].
@@ -226,46 +431,118 @@ impl Item for f32 {
     }
 }
 
+impl Item for f64 {
+    fn from_slice(slice: &[u8]) -> Self {
+        LE::read_f64(slice)
+    }
+    fn zero() -> Self {
+        0.0
+    }
+}
+
+/// A 16-bit IEEE 754 half-precision floating point number.
+///
+/// glTF has no half-float component type, so half-floats are stored in
+/// accessors of type `u16`, for example in application-specific attributes.
+/// Reading such an accessor as `F16` instead of `u16` decodes the values.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct F16(pub u16);
+
+impl F16 {
+    /// Converts the half-float to `f32`, which represents every half-float
+    /// value exactly.
+    pub fn to_f32(self) -> f32 {
+        let sign = u32::from(self.0 >> 15) << 31;
+        let exponent = u32::from((self.0 >> 10) & 0x1f);
+        let mantissa = u32::from(self.0 & 0x3ff);
+        let bits = match (exponent, mantissa) {
+            (0, 0) => sign,
+            (0, _) => {
+                // Subnormal half-floats are normal `f32`s.
+                let shift = mantissa.leading_zeros() - 21;
+                let exponent = 127 - 15 + 1 - shift;
+                sign | (exponent << 23) | ((mantissa << shift) & 0x3ff) << 13
+            }
+            (0x1f, _) => sign | 0x7f80_0000 | (mantissa << 13),
+            _ => sign | ((exponent + 127 - 15) << 23) | (mantissa << 13),
+        };
+        f32::from_bits(bits)
+    }
+}
+
+impl From<F16> for f32 {
+    fn from(value: F16) -> f32 {
+        value.to_f32()
+    }
+}
+
+impl Item for F16 {
+    fn from_slice(slice: &[u8]) -> Self {
+        F16(LE::read_u16(slice))
+    }
+    fn zero() -> Self {
+        F16(0)
+    }
+}
+
 impl<T: Item + Copy> Item for [T; 2] {
     fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 2 * mem::size_of::<T>());
+        assert!(slice.len() >= 2 * T::column_size());
         [
             T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
+            T::from_slice(&slice[T::column_size()..]),
         ]
     }
     fn zero() -> Self {
         [T::zero(); 2]
     }
+    fn size() -> usize {
+        2 * T::column_size()
+    }
+    fn column_size() -> usize {
+        (Self::size() + 3) & !3
+    }
 }
 
 impl<T: Item + Copy> Item for [T; 3] {
     fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 3 * mem::size_of::<T>());
+        assert!(slice.len() >= 3 * T::column_size());
         [
             T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-            T::from_slice(&slice[2 * mem::size_of::<T>()..]),
+            T::from_slice(&slice[T::column_size()..]),
+            T::from_slice(&slice[2 * T::column_size()..]),
         ]
     }
     fn zero() -> Self {
         [T::zero(); 3]
     }
+    fn size() -> usize {
+        3 * T::column_size()
+    }
+    fn column_size() -> usize {
+        (Self::size() + 3) & !3
+    }
 }
 
 impl<T: Item + Copy> Item for [T; 4] {
     fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 4 * mem::size_of::<T>());
+        assert!(slice.len() >= 4 * T::column_size());
         [
             T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-            T::from_slice(&slice[2 * mem::size_of::<T>()..]),
-            T::from_slice(&slice[3 * mem::size_of::<T>()..]),
+            T::from_slice(&slice[T::column_size()..]),
+            T::from_slice(&slice[2 * T::column_size()..]),
+            T::from_slice(&slice[3 * T::column_size()..]),
         ]
     }
     fn zero() -> Self {
         [T::zero(); 4]
     }
+    fn size() -> usize {
+        4 * T::column_size()
+    }
+    fn column_size() -> usize {
+        (Self::size() + 3) & !3
+    }
 }
 
 impl<'a, T: Item> ItemIter<'a, T> {
@@ -289,10 +566,10 @@ impl<'a, 's, T: Item> Iter<'s, T> {
             Some(sparse) => {
                 // Using `if let` here instead of map to preserve the early return behavior.
                 let base_iter = if let Some(view) = accessor.view() {
-                    let stride = view.stride().unwrap_or(mem::size_of::<T>());
+                    let stride = view.stride().unwrap_or(T::size());
 
                     let start = accessor.offset();
-                    let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
+                    let end = start + stride * (accessor.count() - 1) + T::size();
                     let subslice = buffer_view_slice(view, &get_buffer_data)
                         .and_then(|slice| slice.get(start..end))?;
 
@@ -330,35 +607,38 @@ impl<'a, 's, T: Item> Iter<'s, T> {
 
                 let value_iter = {
                     let view = values.view();
-                    let stride = view.stride().unwrap_or(mem::size_of::<T>());
+                    let stride = view.stride().unwrap_or(T::size());
 
                     let start = values.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + mem::size_of::<T>();
+                    let end = start + stride * (sparse_count - 1) + T::size();
                     let subslice = buffer_view_slice(view, &get_buffer_data)
                         .and_then(|slice| slice.get(start..end))?;
 
                     ItemIter::new(subslice, stride)
                 };
 
-                Some(Iter::Sparse(SparseIter::new(
-                    base_iter, index_iter, value_iter,
+                Some(Iter::Sparse(SparseIter::with_count(
+                    base_iter,
+                    index_iter,
+                    value_iter,
+                    accessor.count() as u32,
                 )))
             }
             None => {
-                debug_assert_eq!(mem::size_of::<T>(), accessor.size());
-                debug_assert!(mem::size_of::<T>() > 0);
+                debug_assert_eq!(T::size(), accessor.size());
+                debug_assert!(T::size() > 0);
 
                 accessor.view().and_then(|view| {
-                    let stride = view.stride().unwrap_or(mem::size_of::<T>());
+                    let stride = view.stride().unwrap_or(T::size());
                     debug_assert!(
-                        stride >= mem::size_of::<T>(),
+                        stride >= T::size(),
                         "Mismatch in stride, expected at least {} stride but found {}",
-                        mem::size_of::<T>(),
+                        T::size(),
                         stride
                     );
 
                     let start = accessor.offset();
-                    let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
+                    let end = start + stride * (accessor.count() - 1) + T::size();
                     let subslice = buffer_view_slice(view, &get_buffer_data)
                         .and_then(|slice| slice.get(start..end))?;
 
@@ -380,8 +660,8 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         let stride = if self.data.len() >= self.stride {
             Some(self.stride)
-        } else if self.data.len() >= mem::size_of::<T>() {
-            Some(mem::size_of::<T>())
+        } else if self.data.len() >= T::size() {
+            Some(T::size())
         } else {
             None
         };
@@ -397,7 +677,7 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
 
     fn nth(&mut self, nth: usize) -> Option<Self::Item> {
         if let Some(val_data) = self.data.get(nth * self.stride..) {
-            if val_data.len() >= mem::size_of::<T>() {
+            if val_data.len() >= T::size() {
                 let val = T::from_slice(val_data);
                 self.data = &val_data[self.stride.min(val_data.len())..];
                 Some(val)
@@ -410,7 +690,7 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
     }
 
     fn last(self) -> Option<Self::Item> {
-        if self.data.len() >= mem::size_of::<T>() {
+        if self.data.len() >= T::size() {
             self.data
                 .get((self.data.len() - 1) / self.stride * self.stride..)
                 .map(T::from_slice)
@@ -424,8 +704,8 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.data.len() / self.stride
-            + (self.data.len() % self.stride >= mem::size_of::<T>()) as usize;
+        let hint =
+            self.data.len() / self.stride + (self.data.len() % self.stride >= T::size()) as usize;
         (hint, Some(hint))
     }
 }
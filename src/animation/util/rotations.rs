@@ -134,7 +134,7 @@ impl Cast for I8 {
     }
 }
 
-impl Cast for i8 {
+impl Cast for U8 {
     type Output = [u8; 4];
 
     fn cast_i8(x: [i8; 4]) -> Self::Output {
@@ -44,6 +44,30 @@ pub struct Glb<'a> {
     pub bin: Option<Cow<'a, [u8]>>,
 }
 
+/// Binary glTF contents with the BIN section located but not read.
+///
+/// This avoids loading the BIN section of large files into memory when only
+/// the JSON is required, or when the caller maps or reads the BIN section
+/// lazily itself.
+#[derive(Clone, Debug)]
+pub struct LazyGlb {
+    /// The header section of the `.glb` file.
+    pub header: Header,
+    /// The JSON section of the `.glb` file.
+    pub json: Vec<u8>,
+    /// The location of the optional BIN section of the `.glb` file.
+    pub bin: Option<ChunkLocation>,
+}
+
+/// The location of chunk data within a stream.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ChunkLocation {
+    /// The stream position of the first byte of chunk data.
+    pub offset: u64,
+    /// The length of the chunk data in bytes, excluding the chunk header.
+    pub length: u64,
+}
+
 /// The header section of a .glb file.
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
@@ -301,6 +325,87 @@ impl<'a> Glb<'a> {
     }
 }
 
+impl LazyGlb {
+    /// Reads the header and JSON section of binary glTF from a seekable
+    /// stream, locating the BIN section without reading it.
+    ///
+    /// Reading starts at the current stream position, and the reported BIN
+    /// location is relative to the start of the stream.
+    pub fn from_reader<R: io::Read + io::Seek>(reader: R) -> Result<Self, crate::Error> {
+        Self::read(reader).map_err(crate::Error::Binary)
+    }
+
+    fn read<R: io::Read + io::Seek>(mut reader: R) -> Result<Self, Error> {
+        use self::Error::Io;
+        let start = reader.stream_position().map_err(Io)?;
+        let header = Header::from_reader(&mut reader)?;
+        if header.version != 2 {
+            return Err(Error::Version(header.version));
+        }
+        let end = start + u64::from(header.length);
+
+        let json_h = ChunkHeader::from_reader(&mut reader)?;
+        if let ChunkType::Bin = json_h.ty {
+            return Err(Error::ChunkType(json_h.ty));
+        }
+        // Check the length before allocating, as `split_binary_gltf` does.
+        let available = u64::from(header.length)
+            .saturating_sub((Header::size_of() + mem::size_of::<ChunkHeader>()) as u64);
+        if u64::from(json_h.length) > available {
+            return Err(Error::ChunkLength {
+                ty: json_h.ty,
+                length: json_h.length,
+                length_read: available as usize,
+            });
+        }
+        let mut json = vec![0; json_h.length as usize];
+        reader.read_exact(&mut json).map_err(Io)?;
+
+        let position = start
+            + (Header::size_of() + 2 * mem::size_of::<ChunkHeader>()) as u64
+            + u64::from(json_h.length);
+        // The position is that of the BIN chunk data, after its header.
+        let bin = if position <= end {
+            let bin_h = ChunkHeader::from_reader(&mut reader)?;
+            if let ChunkType::Json = bin_h.ty {
+                return Err(Error::ChunkType(bin_h.ty));
+            }
+            let available = end.saturating_sub(position);
+            if u64::from(bin_h.length) > available {
+                return Err(Error::ChunkLength {
+                    ty: bin_h.ty,
+                    length: bin_h.length,
+                    length_read: available as usize,
+                });
+            }
+            Some(ChunkLocation {
+                offset: position,
+                length: u64::from(bin_h.length),
+            })
+        } else {
+            None
+        };
+
+        Ok(LazyGlb { header, json, bin })
+    }
+
+    /// Reads the BIN section, if any, from the stream it was located in.
+    pub fn read_bin<R: io::Read + io::Seek>(
+        &self,
+        mut reader: R,
+    ) -> Result<Option<Vec<u8>>, crate::Error> {
+        match self.bin {
+            Some(location) => {
+                let mut bin = vec![0; location.length as usize];
+                reader.seek(io::SeekFrom::Start(location.offset))?;
+                reader.read_exact(&mut bin)?;
+                Ok(Some(bin))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
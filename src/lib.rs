@@ -174,6 +174,14 @@ pub use self::texture::Texture;
 use std::path::Path;
 use std::{fs, io, ops, result};
 
+/// Conversion between the normalized representations of a value, following
+/// the equations of the glTF specification.
+///
+/// Integers convert to floats with `f = max(c / MAX, -1.0)` for signed and
+/// `f = c / MAX` for unsigned types, while floats are clamped to the range
+/// of the target type and convert with `c = round(f * MAX)`. Conversions
+/// between integer types go through `f32`, so the most negative signed
+/// value saturates to `-1.0` and then to zero for unsigned targets.
 pub(crate) trait Normalize<T> {
     fn normalize(self) -> T;
 }
@@ -375,6 +383,33 @@ impl Document {
         }
     }
 
+    /// Performs the specification checks, reporting each failure with the
+    /// severity configured by `severities`.
+    ///
+    /// This is useful together with [`Document::from_json_without_validation`]
+    /// for accepting documents with failures that have been downgraded to
+    /// warnings.
+    pub fn validate_report(
+        &self,
+        severities: &json::validation::Severities,
+    ) -> json::validation::Report {
+        self.0.validate_report(severities)
+    }
+
+    /// Performs the additional checks of a validation profile.
+    ///
+    /// Returns `Error::Validation` listing every check that failed.
+    pub fn validate_profile(&self, profile: json::validation::Profile) -> Result<()> {
+        let mut errors = Vec::new();
+        self.0
+            .validate_profile(profile, &mut |path, error| errors.push((path(), error)));
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Validation(errors))
+        }
+    }
+
     /// Returns an `Iterator` that visits the accessors of the glTF asset.
     pub fn accessors(&self) -> iter::Accessors {
         iter::Accessors {
@@ -475,6 +510,19 @@ impl Document {
         })
     }
 
+    /// Returns the names of the variants declared by the
+    /// `KHR_materials_variants` extension, in order.
+    ///
+    /// The variant indices of [`khr_materials_variants::Mapping::variants`]
+    /// index into this list. Returns an empty list if the extension is absent.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn variant_names(&self) -> Vec<&str> {
+        self.variants()
+            .map(|variants| variants.map(|variant| variant.name()).collect())
+            .unwrap_or_default()
+    }
+
     /// Returns an `Iterator` that visits the materials of the glTF asset.
     pub fn materials(&self) -> iter::Materials {
         iter::Materials {
@@ -491,6 +539,62 @@ impl Document {
         }
     }
 
+    /// Calls `f` with every primitive of the glTF asset and its parent mesh,
+    /// in index order.
+    ///
+    /// `Mesh::index` and `Primitive::index` identify the visited items.
+    pub fn visit_primitives<F>(&self, mut f: F)
+    where
+        F: FnMut(&Mesh<'_>, &Primitive<'_>),
+    {
+        for mesh in self.meshes() {
+            for primitive in mesh.primitives() {
+                f(&mesh, &primitive);
+            }
+        }
+    }
+
+    /// Returns an `Iterator` that visits every instance of a mesh in the scenes
+    /// of the glTF asset, alongside its world transform.
+    ///
+    /// The node hierarchy of each scene is traversed, composing the local
+    /// transform of each node with those of its ancestors. A mesh referenced
+    /// by several nodes is visited once per node. Transforms are column-major.
+    pub fn mesh_instances(&self) -> impl Iterator<Item = (Mesh<'_>, [[f32; 4]; 4])> + '_ {
+        fn visit<'a>(
+            node: Node<'a>,
+            parent: math::Matrix4,
+            ancestors: &mut Vec<usize>,
+            instances: &mut Vec<(Mesh<'a>, [[f32; 4]; 4])>,
+        ) {
+            if ancestors.contains(&node.index()) {
+                return;
+            }
+            let world = parent * math::Matrix4::from_array(node.transform().matrix());
+            if let Some(mesh) = node.mesh() {
+                instances.push((mesh, world.as_array()));
+            }
+            ancestors.push(node.index());
+            for child in node.children() {
+                visit(child, world, ancestors, instances);
+            }
+            ancestors.pop();
+        }
+
+        let mut instances = Vec::new();
+        for scene in self.scenes() {
+            for node in scene.nodes() {
+                visit(
+                    node,
+                    math::Matrix4::identity(),
+                    &mut Vec::new(),
+                    &mut instances,
+                );
+            }
+        }
+        instances.into_iter()
+    }
+
     /// Returns an `Iterator` that visits the nodes of the glTF asset.
     pub fn nodes(&self) -> iter::Nodes {
         iter::Nodes {
@@ -629,31 +733,31 @@ impl Normalize<i8> for i8 {
 
 impl Normalize<u8> for i8 {
     fn normalize(self) -> u8 {
-        self.max(0) as u8 * 2
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
 impl Normalize<i16> for i8 {
     fn normalize(self) -> i16 {
-        self as i16 * 0x100
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
 impl Normalize<u16> for i8 {
     fn normalize(self) -> u16 {
-        self.max(0) as u16 * 0x200
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
 impl Normalize<f32> for i8 {
     fn normalize(self) -> f32 {
-        (self as f32 * 127.0_f32.recip()).max(-1.0)
+        (self as f32 / 127.0).max(-1.0)
     }
 }
 
 impl Normalize<i8> for u8 {
     fn normalize(self) -> i8 {
-        (self / 2) as i8
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
@@ -665,31 +769,31 @@ impl Normalize<u8> for u8 {
 
 impl Normalize<i16> for u8 {
     fn normalize(self) -> i16 {
-        self as i16 * 0x80
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
 impl Normalize<u16> for u8 {
     fn normalize(self) -> u16 {
-        self as u16 * 0x100
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
 impl Normalize<f32> for u8 {
     fn normalize(self) -> f32 {
-        self as f32 * 255.0_f32.recip()
+        self as f32 / 255.0
     }
 }
 
 impl Normalize<i8> for i16 {
     fn normalize(self) -> i8 {
-        (self / 0x100) as i8
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
 impl Normalize<u8> for i16 {
     fn normalize(self) -> u8 {
-        (self.max(0) / 0x80) as u8
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
@@ -701,31 +805,31 @@ impl Normalize<i16> for i16 {
 
 impl Normalize<u16> for i16 {
     fn normalize(self) -> u16 {
-        self.max(0) as u16 * 2
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
 impl Normalize<f32> for i16 {
     fn normalize(self) -> f32 {
-        (self as f32 * 32767.0_f32.recip()).max(-1.0)
+        (self as f32 / 32767.0).max(-1.0)
     }
 }
 
 impl Normalize<i8> for u16 {
     fn normalize(self) -> i8 {
-        (self / 0x200) as i8
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
 impl Normalize<u8> for u16 {
     fn normalize(self) -> u8 {
-        (self / 0x100) as u8
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
 impl Normalize<i16> for u16 {
     fn normalize(self) -> i16 {
-        (self / 2) as i16
+        Normalize::<f32>::normalize(self).normalize()
     }
 }
 
@@ -737,31 +841,31 @@ impl Normalize<u16> for u16 {
 
 impl Normalize<f32> for u16 {
     fn normalize(self) -> f32 {
-        self as f32 * 65535.0_f32.recip()
+        self as f32 / 65535.0
     }
 }
 
 impl Normalize<i8> for f32 {
     fn normalize(self) -> i8 {
-        (self * 127.0) as i8
+        (self.clamp(-1.0, 1.0) * 127.0).round() as i8
     }
 }
 
 impl Normalize<u8> for f32 {
     fn normalize(self) -> u8 {
-        (self.max(0.0) * 255.0) as u8
+        (self.clamp(0.0, 1.0) * 255.0).round() as u8
     }
 }
 
 impl Normalize<i16> for f32 {
     fn normalize(self) -> i16 {
-        (self * 32767.0) as i16
+        (self.clamp(-1.0, 1.0) * 32767.0).round() as i16
     }
 }
 
 impl Normalize<u16> for f32 {
     fn normalize(self) -> u16 {
-        (self.max(0.0) * 65535.0) as u16
+        (self.clamp(0.0, 1.0) * 65535.0).round() as u16
     }
 }
 
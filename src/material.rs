@@ -197,6 +197,33 @@ impl<'a> Material<'a> {
         })
     }
 
+    /// Returns the texture co-ordinate sets read by the core textures of the
+    /// material, in ascending order without duplicates.
+    ///
+    /// The core textures are the base color, metallic-roughness, normal,
+    /// occlusion, and emissive textures.
+    pub fn tex_coord_sets(&self) -> Vec<u32> {
+        let pbr = self.pbr_metallic_roughness();
+        let mut sets: Vec<u32> = pbr
+            .base_color_texture()
+            .map(|info| info.tex_coord())
+            .into_iter()
+            .chain(
+                pbr.metallic_roughness_texture()
+                    .map(|info| info.tex_coord()),
+            )
+            .chain(self.normal_texture().map(|normal| normal.tex_coord()))
+            .chain(
+                self.occlusion_texture()
+                    .map(|occlusion| occlusion.tex_coord()),
+            )
+            .chain(self.emissive_texture().map(|info| info.tex_coord()))
+            .collect();
+        sets.sort_unstable();
+        sets.dedup();
+        sets
+    }
+
     /// The emissive color of the material.
     ///
     /// The default value is `[0.0, 0.0, 0.0]`.
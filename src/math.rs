@@ -163,7 +163,6 @@ impl Vector4 {
         [self.x, self.y, self.z, self.w]
     }
 
-    #[cfg(test)]
     pub fn from_array([x, y, z, w]: [f32; 4]) -> Self {
         Self { x, y, z, w }
     }
@@ -250,7 +249,6 @@ impl Matrix4 {
         }
     }
 
-    #[cfg(test)]
     pub fn from_array([x, y, z, w]: [[f32; 4]; 4]) -> Matrix4 {
         Matrix4 {
             x: Vector4::from_array(x),
@@ -260,6 +258,11 @@ impl Matrix4 {
         }
     }
 
+    /// Create an identity matrix.
+    pub fn identity() -> Matrix4 {
+        Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0)
+    }
+
     /// Create a homogeneous transformation matrix from a translation vector.
     #[rustfmt::skip]
     pub fn from_translation(v: Vector3) -> Matrix4 {
@@ -63,6 +63,8 @@ use crate::accessor;
 
 pub use json::mesh::{Mode, Semantic};
 use json::validation::Checked;
+#[cfg(feature = "utils")]
+use std::collections::HashMap;
 
 /// Vertex attribute data.
 pub type Attribute<'a> = (Semantic, Accessor<'a>);
@@ -80,6 +82,75 @@ pub struct Bounds<T> {
     pub max: T,
 }
 
+/// The parameters of the `glDrawArrays` or `glDrawElements` call that renders
+/// a primitive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DrawCall {
+    /// The GL primitive topology, for example `GL_TRIANGLES`.
+    pub mode_gl: u32,
+
+    /// The number of indices, or of vertices if the primitive is not indexed.
+    pub count: usize,
+
+    /// Whether the primitive is drawn with `glDrawElements`.
+    pub indexed: bool,
+
+    /// The component type of the indices, if the primitive is indexed.
+    pub index_type: Option<crate::accessor::DataType>,
+
+    /// The byte offset of the first index from the start of its buffer, or 0
+    /// if the primitive is not indexed.
+    pub offset: usize,
+}
+
+/// The outcome of [`Reader::verify_checksum`].
+#[cfg(feature = "extras")]
+#[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChecksumStatus {
+    /// The data matches the stored checksum.
+    Match,
+
+    /// The data does not match the stored checksum.
+    Mismatch,
+
+    /// Some of the data could not be resolved, so it was not compared.
+    Unresolved,
+}
+
+/// The differences between two primitives, as returned by
+/// [`Primitive::diff`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PrimitiveDiff {
+    /// The semantics of the vertex attributes present only in the other
+    /// primitive, in canonical order.
+    pub added: Vec<Semantic>,
+
+    /// The semantics of the vertex attributes present only in this primitive,
+    /// in canonical order.
+    pub removed: Vec<Semantic>,
+
+    /// The semantics of the vertex attributes present in both primitives
+    /// whose accessors differ, in canonical order.
+    pub changed: Vec<Semantic>,
+
+    /// Whether the indices differ, or only one primitive is indexed.
+    pub indices: bool,
+
+    /// Whether the material indices differ.
+    pub material: bool,
+
+    /// Whether the modes differ.
+    pub mode: bool,
+}
+
+impl PrimitiveDiff {
+    /// Returns `true` if no difference was found.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// A set of primitives to be rendered.
 #[derive(Clone, Debug)]
 pub struct Mesh<'a> {
@@ -129,6 +200,27 @@ where
     pub(crate) get_buffer_data: F,
 }
 
+/// The 64-bit FNV-1a hash, used for hashes that must be stable across
+/// platforms and releases.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
 impl<'a> Mesh<'a> {
     /// Constructs a `Mesh`.
     pub(crate) fn new(document: &'a Document, index: usize, json: &'a json::mesh::Mesh) -> Self {
@@ -149,6 +241,13 @@ impl<'a> Mesh<'a> {
         &self.json.extras
     }
 
+    /// Returns the morph target names stored in `extras.targetNames`, if any.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn target_names(&self) -> Option<Vec<String>> {
+        self.json.target_names()
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
@@ -168,6 +267,33 @@ impl<'a> Mesh<'a> {
     pub fn weights(&self) -> Option<&'a [f32]> {
         self.json.weights.as_deref()
     }
+
+    /// Returns the union of the bounds of the primitives.
+    ///
+    /// Primitives whose bounds are unavailable are skipped, see
+    /// [`Primitive::try_bounding_box`]. Returns `None` if no primitive has
+    /// bounds.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.primitives()
+            .filter_map(|primitive| primitive.try_bounding_box())
+            .fold(None, |union: Option<BoundingBox>, bounds| {
+                Some(match union {
+                    Some(Bounds { min, max }) => Bounds {
+                        min: [
+                            min[0].min(bounds.min[0]),
+                            min[1].min(bounds.min[1]),
+                            min[2].min(bounds.min[2]),
+                        ],
+                        max: [
+                            max[0].max(bounds.max[0]),
+                            max[1].max(bounds.max[1]),
+                            max[2].max(bounds.max[2]),
+                        ],
+                    },
+                    None => bounds,
+                })
+            })
+    }
 }
 
 impl<'a> Primitive<'a> {
@@ -195,11 +321,169 @@ impl<'a> Primitive<'a> {
         Bounds { min, max }
     }
 
+    /// Returns the bounds of the `POSITION` vertex attribute from the `min`
+    /// and `max` values of its accessor.
+    ///
+    /// Unlike [`Primitive::bounding_box`], this returns `None` instead of
+    /// panicking if the attribute or either value is absent or malformed.
+    pub fn try_bounding_box(&self) -> Option<BoundingBox> {
+        let accessor = self.get(&Semantic::Positions)?;
+        let min: [f32; 3] = json::deserialize::from_value(accessor.min()?).ok()?;
+        let max: [f32; 3] = json::deserialize::from_value(accessor.max()?).ok()?;
+        Some(Bounds { min, max })
+    }
+
+    /// Returns the bounds of the `POSITION` vertex attribute, scanning the
+    /// vertex positions if the accessor lacks `min` and `max` values.
+    ///
+    /// Use [`Primitive::try_bounding_box`] to only consult the accessor
+    /// metadata, or [`Reader::compute_bounding_box`] to always scan.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn aabb_iter<'s, F>(&'a self, get_buffer_data: F) -> Option<BoundingBox>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        self.try_bounding_box()
+            .or_else(|| self.reader(get_buffer_data).compute_bounding_box())
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 
+    /// Returns the total size in bytes of the data referenced by the
+    /// primitive's attribute, index, and morph target accessors.
+    ///
+    /// Each accessor contributes its element size multiplied by its element
+    /// count, so sparse accessors are counted at their dense size. Accessors
+    /// are counted once per reference: data shared with other primitives, or
+    /// referenced more than once by this one, is counted again each time.
+    pub fn byte_size(&self) -> usize {
+        let targets = self.morph_targets().flat_map(|target| {
+            target
+                .positions()
+                .into_iter()
+                .chain(target.normals())
+                .chain(target.tangents())
+        });
+        self.attributes()
+            .map(|(_, accessor)| accessor)
+            .chain(self.indices())
+            .chain(targets)
+            .map(|accessor| accessor.size() * accessor.count())
+            .sum()
+    }
+
+    /// Returns a bitmask of the common vertex attributes present in the
+    /// primitive, for use as a cheap shader variant key.
+    ///
+    /// | Bit | Attribute    |
+    /// |-----|--------------|
+    /// | 0   | `POSITION`   |
+    /// | 1   | `NORMAL`     |
+    /// | 2   | `TANGENT`    |
+    /// | 3   | `COLOR_0`    |
+    /// | 4   | `TEXCOORD_0` |
+    /// | 5   | `TEXCOORD_1` |
+    /// | 6   | `JOINTS_0`   |
+    /// | 7   | `WEIGHTS_0`  |
+    ///
+    /// All other bits are zero.
+    pub fn attribute_mask(&self) -> u32 {
+        let semantics = [
+            Semantic::Positions,
+            Semantic::Normals,
+            Semantic::Tangents,
+            Semantic::Colors(0),
+            Semantic::TexCoords(0),
+            Semantic::TexCoords(1),
+            Semantic::Joints(0),
+            Semantic::Weights(0),
+        ];
+        semantics
+            .iter()
+            .enumerate()
+            .filter(|(_, semantic)| self.get(semantic).is_some())
+            .fold(0, |mask, (bit, _)| mask | 1 << bit)
+    }
+
+    /// Returns `true` if the primitive has both `JOINTS_0` and `WEIGHTS_0`
+    /// attributes.
+    pub fn is_skinned(&self) -> bool {
+        self.get(&Semantic::Joints(0)).is_some() && self.get(&Semantic::Weights(0)).is_some()
+    }
+
+    /// Returns `true` if the primitive has at least one morph target.
+    pub fn is_morphed(&self) -> bool {
+        self.json.morph_target_count() > 0
+    }
+
+    /// Returns the pairs of vertex attributes whose data overlap in memory.
+    ///
+    /// Legitimate interleaving, where attributes share a buffer view and the
+    /// byte stride accounts for each of them, is not reported. Overlaps of
+    /// this kind usually indicate a bug in the exporter. Attributes sharing
+    /// the same accessor are not reported either.
+    ///
+    /// The pairs are ordered by semantic name.
+    pub fn overlapping_attributes(&self) -> Vec<(Semantic, Semantic)> {
+        struct Range {
+            buffer: usize,
+            start: usize,
+            end: usize,
+            stride: usize,
+            size: usize,
+        }
+
+        let mut attributes: Vec<(Semantic, Accessor<'a>)> = self.attributes().collect();
+        attributes.sort_by_key(|(semantic, _)| semantic.to_string());
+        let ranges: Vec<Option<Range>> = attributes
+            .iter()
+            .map(|(_, accessor)| {
+                let view = accessor.view()?;
+                let size = accessor.size();
+                let stride = view.stride().unwrap_or(size);
+                let start = view.offset() + accessor.offset();
+                let end = start + stride * accessor.count().saturating_sub(1) + size;
+                Some(Range {
+                    buffer: view.buffer().index(),
+                    start,
+                    end,
+                    stride,
+                    size,
+                })
+            })
+            .collect();
+
+        let mut overlapping = Vec::new();
+        for i in 0..attributes.len() {
+            for j in (i + 1)..attributes.len() {
+                if attributes[i].1.index() == attributes[j].1.index() {
+                    continue;
+                }
+                let (a, b) = match (&ranges[i], &ranges[j]) {
+                    (Some(a), Some(b)) => (a, b),
+                    _ => continue,
+                };
+                if a.buffer != b.buffer || a.end <= b.start || b.end <= a.start {
+                    continue;
+                }
+                if a.stride == b.stride {
+                    // Elements are disjoint if `b` starts after the end of an
+                    // `a` element and ends before the start of the next one.
+                    let offset = (b.start + a.stride - a.start % a.stride) % a.stride;
+                    if offset >= a.size && offset + b.size <= a.stride {
+                        continue;
+                    }
+                }
+                overlapping.push((attributes[i].0.clone(), attributes[j].0.clone()));
+            }
+        }
+        overlapping
+    }
+
     /// Return the accessor with the given semantic.
     pub fn get(&self, semantic: &Semantic) -> Option<Accessor<'a>> {
         self.json
@@ -221,6 +505,123 @@ impl<'a> Primitive<'a> {
             .map(|index| self.mesh.document.accessors().nth(index.value()).unwrap())
     }
 
+    /// Returns the number of indices, if the primitive is indexed.
+    pub fn index_count(&self) -> Option<usize> {
+        self.indices().map(|accessor| accessor.count())
+    }
+
+    /// Returns the component type of the indices, if the primitive is indexed.
+    pub fn index_component_type(&self) -> Option<crate::accessor::DataType> {
+        self.indices().map(|accessor| accessor.data_type())
+    }
+
+    /// Returns whether this primitive and `other` read every vertex attribute,
+    /// including morph target displacements, from the same accessors.
+    ///
+    /// Such primitives can be drawn from the same bound vertex buffers, for
+    /// example with different index ranges. Both primitives must belong to
+    /// the same document.
+    pub fn shares_vertices_with(&self, other: &Primitive) -> bool {
+        let targets = |primitive: &Primitive| -> Vec<_> {
+            primitive
+                .json
+                .targets
+                .iter()
+                .flatten()
+                .map(|target| (target.positions, target.normals, target.tangents))
+                .collect()
+        };
+        self.json.attributes == other.json.attributes && targets(self) == targets(other)
+    }
+
+    /// Compares the vertex attributes, indices, material, and mode of this
+    /// primitive with those of `other`, which may belong to another document.
+    ///
+    /// Accessors are compared by their description rather than their data:
+    /// two accessors differ if their component type, type, normalization,
+    /// element count, `min`, or `max` differ. Morph targets are not compared.
+    pub fn diff(&self, other: &Primitive) -> PrimitiveDiff {
+        let describe = |accessor: &Accessor| {
+            (
+                accessor.data_type(),
+                accessor.dimensions(),
+                accessor.normalized(),
+                accessor.count(),
+                accessor.min(),
+                accessor.max(),
+            )
+        };
+        let differ =
+            |a: Option<&Accessor>, b: Option<&Accessor>| a.map(describe) != b.map(describe);
+
+        let mut diff = PrimitiveDiff::default();
+        for (semantic, accessor) in self.attributes() {
+            match other.get(&semantic) {
+                Some(theirs) if differ(Some(&accessor), Some(&theirs)) => {
+                    diff.changed.push(semantic)
+                }
+                Some(_) => {}
+                None => diff.removed.push(semantic),
+            }
+        }
+        diff.added = other
+            .attributes()
+            .map(|(semantic, _)| semantic)
+            .filter(|semantic| self.get(semantic).is_none())
+            .collect();
+        diff.added.sort();
+        diff.removed.sort();
+        diff.changed.sort();
+        diff.indices = differ(self.indices().as_ref(), other.indices().as_ref());
+        diff.material = self.material().index() != other.material().index();
+        diff.mode = self.mode() != other.mode();
+        diff
+    }
+
+    /// Returns whether every vertex of the primitive can be referenced by
+    /// `u16` indices.
+    ///
+    /// This is the case for at most 65535 vertices, since the specification
+    /// reserves the maximum index value 65535 for primitive restart. The
+    /// vertex count is that of the `POSITION` accessor.
+    pub fn indices_fit_u16(&self) -> bool {
+        self.get(&Semantic::Positions)
+            .map(|accessor| accessor.count())
+            .unwrap_or(0)
+            <= usize::from(u16::MAX)
+    }
+
+    /// Returns the parameters of the GL draw call that renders this primitive.
+    ///
+    /// Non-indexed primitives draw the vertices of the `POSITION` accessor,
+    /// or none if it is absent.
+    pub fn draw_call_params(&self) -> DrawCall {
+        match self.indices() {
+            Some(indices) => DrawCall {
+                mode_gl: self.mode().as_gl_enum(),
+                count: indices.count(),
+                indexed: true,
+                index_type: Some(indices.data_type()),
+                offset: indices.view().map_or(0, |view| view.offset()) + indices.offset(),
+            },
+            None => DrawCall {
+                mode_gl: self.mode().as_gl_enum(),
+                count: self
+                    .get(&Semantic::Positions)
+                    .map_or(0, |positions| positions.count()),
+                indexed: false,
+                index_type: None,
+                offset: 0,
+            },
+        }
+    }
+
+    /// Returns whether the vertex attributes required by the specification,
+    /// which is only `POSITION`, are present.
+    pub fn has_required_attributes(&self) -> bool {
+        self.json.has_required_attributes()
+    }
+
     /// Returns an `Iterator` that visits the vertex attributes.
     pub fn attributes(&self) -> iter::Attributes<'a> {
         iter::Attributes {
@@ -240,10 +641,22 @@ impl<'a> Primitive<'a> {
     }
 
     /// The type of primitives to render.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the primitive has an extension mode, see
+    /// [`Primitive::extension_mode`].
     pub fn mode(&self) -> Mode {
         self.json.mode.unwrap()
     }
 
+    /// The raw `mode` value if it is outside of the specification, such as
+    /// one added by a vendor extension.
+    #[cfg(feature = "extension_modes")]
+    pub fn extension_mode(&self) -> Option<u32> {
+        self.json.extension_mode()
+    }
+
     /// Returns an `Iterator` that visits the morph targets of the primitive.
     pub fn morph_targets(&self) -> iter::MorphTargets<'a> {
         if let Some(slice) = self.json.targets.as_ref() {
@@ -259,6 +672,27 @@ impl<'a> Primitive<'a> {
         }
     }
 
+    /// Returns an `Iterator` that pairs each morph target of the primitive with
+    /// its weight, typically `Mesh::weights` or the weights of the instantiating
+    /// node.
+    ///
+    /// Returns `None` if the number of weights does not match the number of
+    /// morph targets.
+    pub fn weighted_morph_targets<'w>(
+        &self,
+        weights: &'w [f32],
+    ) -> Option<impl Iterator<Item = (f32, MorphTarget<'a>)> + 'w>
+    where
+        'a: 'w,
+    {
+        let targets = self.morph_targets();
+        if targets.len() == weights.len() {
+            Some(weights.iter().copied().zip(targets))
+        } else {
+            None
+        }
+    }
+
     /// Get the material variants.
     #[cfg(feature = "KHR_materials_variants")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
@@ -277,6 +711,20 @@ impl<'a> Primitive<'a> {
         }
     }
 
+    /// Returns the material used by this primitive for the variant with the
+    /// given index.
+    ///
+    /// Falls back to [`Primitive::material`], which may be the default
+    /// material, when no mapping of this primitive covers the variant.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn material_for_variant(&self, variant: u32) -> Material<'a> {
+        self.mappings()
+            .find(|mapping| mapping.variants().contains(&variant))
+            .map(|mapping| mapping.material())
+            .unwrap_or_else(|| self.material())
+    }
+
     /// Constructs the primitive reader.
     #[cfg(feature = "utils")]
     #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
@@ -289,6 +737,23 @@ impl<'a> Primitive<'a> {
             get_buffer_data,
         }
     }
+
+    /// Constructs the primitive reader from a buffer resolver.
+    ///
+    /// The resolver maps a buffer index to its data and is called on demand
+    /// whenever the reader needs to access a buffer, so buffers need not be
+    /// loaded up front.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader_with_resolver<'s, R>(
+        &'a self,
+        buffer_resolver: R,
+    ) -> Reader<'a, 's, impl Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>>
+    where
+        R: Clone + Fn(usize) -> Option<&'s [u8]>,
+    {
+        self.reader(move |buffer: Buffer<'a>| buffer_resolver(buffer.index()))
+    }
 }
 
 #[cfg(feature = "utils")]
@@ -296,6 +761,113 @@ impl<'a, 's, F> Reader<'a, 's, F>
 where
     F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
+    /// Returns the raw bytes of the vertex attribute with the given semantic,
+    /// without copying, if the data is tightly packed.
+    ///
+    /// `None` is returned if the attribute is absent, sparse, or interleaved
+    /// with other data. glTF buffers are little-endian, so the bytes may only
+    /// be reinterpreted directly (for example, for GPU upload) on
+    /// little-endian platforms.
+    pub fn attribute_bytes(&self, semantic: &Semantic) -> Option<&'s [u8]> {
+        self.primitive.get(semantic).and_then(|accessor| {
+            accessor::util::packed_slice(&accessor, self.get_buffer_data.clone())
+        })
+    }
+
+    /// Returns the raw bytes of the vertex positions, without copying, if the
+    /// data is tightly packed.
+    ///
+    /// See [`Reader::attribute_bytes`] for details.
+    pub fn positions_bytes(&self) -> Option<&'s [u8]> {
+        self.attribute_bytes(&Semantic::Positions)
+    }
+
+    /// Computes the bounds of the vertex positions by scanning them.
+    ///
+    /// Returns `None` if the primitive has no positions.
+    pub fn compute_bounding_box(&self) -> Option<BoundingBox> {
+        let mut positions = self.read_positions()?;
+        let first = positions.next()?;
+        Some(positions.fold(
+            Bounds {
+                min: first,
+                max: first,
+            },
+            |Bounds { min, max }, [x, y, z]| Bounds {
+                min: [min[0].min(x), min[1].min(y), min[2].min(z)],
+                max: [max[0].max(x), max[1].max(y), max[2].max(z)],
+            },
+        ))
+    }
+
+    /// Computes a sphere enclosing the vertex positions, returned as its
+    /// center and radius.
+    ///
+    /// The sphere is found with Ritter's algorithm, which requires two passes
+    /// over the positions and is typically within a few percent of the
+    /// smallest enclosing sphere. Returns `None` if the primitive has no
+    /// positions.
+    pub fn bounding_sphere(&self) -> Option<([f32; 3], f32)> {
+        let positions: Vec<[f32; 3]> = self.read_positions()?.collect();
+        let distance_squared = |a: [f32; 3], b: [f32; 3]| {
+            (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)
+        };
+        let farthest = |from: [f32; 3]| {
+            positions.iter().copied().fold(from, |best, p| {
+                if distance_squared(p, from) > distance_squared(best, from) {
+                    p
+                } else {
+                    best
+                }
+            })
+        };
+
+        // Start from the sphere spanning two points that are far apart.
+        let a = farthest(*positions.first()?);
+        let b = farthest(a);
+        let mut center = [
+            (a[0] + b[0]) / 2.0,
+            (a[1] + b[1]) / 2.0,
+            (a[2] + b[2]) / 2.0,
+        ];
+        let mut radius = distance_squared(a, b).sqrt() / 2.0;
+
+        // Grow the sphere just enough to include every point outside of it.
+        for &p in &positions {
+            let distance = distance_squared(p, center).sqrt();
+            if distance > radius {
+                let grown = (radius + distance) / 2.0;
+                let shift = (grown - radius) / distance;
+                for (c, p) in center.iter_mut().zip(p.iter()) {
+                    *c += (p - *c) * shift;
+                }
+                radius = grown;
+            }
+        }
+
+        // Absorb rounding errors so that the sphere contains every point.
+        let distance = positions
+            .iter()
+            .map(|&p| distance_squared(p, center).sqrt())
+            .fold(radius, f32::max);
+        Some((center, distance))
+    }
+
+    /// Borrows the vertex indices of a primitive without copying them, if
+    /// they are `u16` values tightly packed in memory.
+    ///
+    /// Returns `None` for other index types, sparse or strided index
+    /// accessors, data not aligned to two bytes, and on big-endian targets,
+    /// in which case [`Reader::read_indices`] reads the indices instead.
+    pub fn indices_u16_slice(&self) -> Option<&'s [u16]> {
+        let accessor = self.primitive.indices()?;
+        if accessor.data_type() != accessor::DataType::U16 || cfg!(target_endian = "big") {
+            return None;
+        }
+        let bytes = accessor::util::packed_slice(&accessor, self.get_buffer_data.clone())?;
+        bytemuck::try_cast_slice(bytes).ok()
+    }
+
     /// Visits the vertex positions of a primitive.
     pub fn read_positions(&self) -> Option<util::ReadPositions<'s>> {
         self.primitive
@@ -303,6 +875,24 @@ where
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 
+    /// Visits the vertex positions of a primitive in double precision.
+    ///
+    /// Positions are read from the copy defined by the custom
+    /// `EXT_accessor_double` extension if the accessor has one, and are
+    /// otherwise widened from the single precision data.
+    #[cfg(feature = "EXT_accessor_double")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_accessor_double")))]
+    pub fn read_positions_f64(&self) -> Option<util::ReadPositionsF64<'s>> {
+        use self::util::ReadPositionsF64;
+        let accessor = self.primitive.get(&Semantic::Positions)?;
+        if accessor.double_precision().is_some() {
+            accessor::util::double_precision_iter(&accessor, self.get_buffer_data.clone())
+                .map(ReadPositionsF64::F64)
+        } else {
+            accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadPositionsF64::F32)
+        }
+    }
+
     /// Visits the vertex normals of a primitive.
     pub fn read_normals(&self) -> Option<util::ReadNormals<'s>> {
         self.primitive
@@ -310,11 +900,113 @@ where
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 
+    /// Reads the vertex positions of a primitive, decoding chunks of vertices
+    /// in parallel.
+    ///
+    /// The result is identical to collecting [`Reader::read_positions`].
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn read_positions_par(&self) -> Option<Vec<[f32; 3]>> {
+        let accessor = self.primitive.get(&Semantic::Positions)?;
+        accessor::util::read_par(accessor, self.get_buffer_data.clone())
+    }
+
+    /// Reads the vertex positions of a primitive with each morph target's
+    /// position displacements added, scaled by the corresponding weight.
+    ///
+    /// Returns `None` if the primitive has no positions or if the number of
+    /// weights does not match the number of morph targets.
+    pub fn apply_morphs(&self, weights: &[f32]) -> Option<Vec<[f32; 3]>> {
+        let base = self.read_positions()?.collect();
+        self.blend_morph_targets(base, weights, |(positions, _, _)| positions)
+    }
+
+    /// Reads the vertex normals of a primitive with each morph target's
+    /// normal displacements added, scaled by the corresponding weight.
+    ///
+    /// The blended normals are not renormalized.  Returns `None` if the
+    /// primitive has no normals or if the number of weights does not match
+    /// the number of morph targets.
+    pub fn apply_normal_morphs(&self, weights: &[f32]) -> Option<Vec<[f32; 3]>> {
+        let base = self.read_normals()?.collect();
+        self.blend_morph_targets(base, weights, |(_, normals, _)| normals)
+    }
+
+    fn blend_morph_targets<S>(
+        &self,
+        mut base: Vec<[f32; 3]>,
+        weights: &[f32],
+        select: S,
+    ) -> Option<Vec<[f32; 3]>>
+    where
+        S: Fn(
+            <util::ReadMorphTargets<'a, 's, F> as Iterator>::Item,
+        ) -> Option<util::ReadPositionDisplacements<'s>>,
+    {
+        if self.primitive.morph_targets().len() != weights.len() {
+            return None;
+        }
+        for (weight, target) in weights.iter().zip(self.read_morph_targets()) {
+            let displacements = match select(target) {
+                Some(displacements) => displacements,
+                None => continue,
+            };
+            for (vertex, displacement) in base.iter_mut().zip(displacements) {
+                for (component, offset) in vertex.iter_mut().zip(displacement.iter()) {
+                    *component += weight * offset;
+                }
+            }
+        }
+        Some(base)
+    }
+
+    /// Reads the vertex normals of a primitive, rescaling each to unit length.
+    ///
+    /// Normals of zero length are left unchanged.
+    pub fn read_normals_normalized(&self) -> Option<Vec<[f32; 3]>> {
+        self.read_normals().map(|normals| {
+            normals
+                .map(|n| {
+                    let length = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+                    if length > 0.0 {
+                        [n[0] / length, n[1] / length, n[2] / length]
+                    } else {
+                        n
+                    }
+                })
+                .collect()
+        })
+    }
+
+    /// Returns `true` if any vertex normal's length differs from one by more
+    /// than `epsilon`.
+    pub fn has_non_unit_normals(&self, epsilon: f32) -> bool {
+        match self.read_normals() {
+            Some(mut normals) => normals
+                .any(|n| ((n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt() - 1.0).abs() > epsilon),
+            None => false,
+        }
+    }
+
     /// Visits the vertex tangents of a primitive.
+    ///
+    /// With the `lenient` feature, tangents stored as `VEC3` by
+    /// non-conformant assets are given a handedness of +1; otherwise they are
+    /// not read.
     pub fn read_tangents(&self) -> Option<util::ReadTangents<'s>> {
-        self.primitive
-            .get(&Semantic::Tangents)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+        use self::util::ReadTangents;
+        use accessor::Dimensions;
+        let accessor = self.primitive.get(&Semantic::Tangents)?;
+        match accessor.dimensions() {
+            Dimensions::Vec4 => {
+                accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTangents::Vec4)
+            }
+            #[cfg(feature = "lenient")]
+            Dimensions::Vec3 => {
+                accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTangents::Vec3)
+            }
+            _ => None,
+        }
     }
 
     /// Visits the vertex colors of a primitive.
@@ -343,6 +1035,73 @@ where
             )
     }
 
+    /// Visits the vertex colors of a primitive as RGB, discarding any alpha
+    /// channel present in the source.
+    pub fn read_colors_rgb(
+        &self,
+        set: u32,
+    ) -> Option<util::colors::CastingIter<'s, util::colors::RgbF32>> {
+        self.read_colors(set).map(|colors| colors.into_rgb_f32())
+    }
+
+    /// Visits the vertex colors of a primitive as RGBA, expanding RGB sources
+    /// with an alpha of `1.0`.
+    ///
+    /// This is useful for renderers with a fixed vertex layout.
+    pub fn read_colors_rgba(
+        &self,
+        set: u32,
+    ) -> Option<util::colors::CastingIter<'s, util::colors::RgbaF32>> {
+        self.read_colors(set).map(|colors| colors.into_rgba_f32())
+    }
+
+    /// Visits the vertex colors of a primitive as RGBA, expanding RGB sources
+    /// with the given `alpha`.
+    ///
+    /// This suits assets whose RGB colors are meant to be, for example, fully
+    /// transparent.
+    pub fn read_colors_rgba_with_alpha(
+        &self,
+        set: u32,
+        alpha: f32,
+    ) -> Option<util::colors::WithAlpha<'s>> {
+        self.read_colors(set)
+            .map(|colors| colors.into_rgba_f32_with_alpha(alpha))
+    }
+
+    /// Reads the vertex colors of sets 0 and 1 as RGBA f32, paired per vertex.
+    ///
+    /// Returns `None` unless both sets are present with the same number of
+    /// vertices.
+    pub fn read_color_sets(&self) -> Option<Vec<[[f32; 4]; 2]>> {
+        let first = self.read_colors_rgba(0)?;
+        let second = self.read_colors_rgba(1)?;
+        if first.len() != second.len() {
+            return None;
+        }
+        Some(first.zip(second).map(|(a, b)| [a, b]).collect())
+    }
+
+    /// Combines the vertex colors of sets 0 and 1 of each vertex.
+    ///
+    /// Returns `None` under the same conditions as [`Reader::read_color_sets`].
+    pub fn blend_color_sets(&self, blend: util::colors::ColorBlend) -> Option<Vec<[f32; 4]>> {
+        let pairs = self.read_color_sets()?;
+        Some(pairs.into_iter().map(|[a, b]| blend.apply(a, b)).collect())
+    }
+
+    /// Returns whether the vertex colors of the given set have an alpha
+    /// channel, that is, whether the accessor is `VEC4` rather than `VEC3`.
+    ///
+    /// Returns `None` if the set is absent or has any other element type.
+    pub fn color_has_alpha(&self, set: u32) -> Option<bool> {
+        match self.primitive.get(&Semantic::Colors(set))?.dimensions() {
+            accessor::Dimensions::Vec3 => Some(false),
+            accessor::Dimensions::Vec4 => Some(true),
+            _ => None,
+        }
+    }
+
     /// Visits the vertex draw sequence of a primitive.
     pub fn read_indices(&self) -> Option<util::ReadIndices<'s>> {
         use self::util::ReadIndices;
@@ -413,6 +1172,778 @@ where
             })
     }
 
+    /// Visits the feature IDs of the primitive as defined by the
+    /// `EXT_mesh_features` extension.
+    #[cfg(feature = "EXT_mesh_features")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_features")))]
+    pub fn read_feature_ids(&self, set: u32) -> Option<util::ReadFeatureIds<'s>> {
+        use self::util::ReadFeatureIds;
+        use accessor::DataType;
+        self.primitive
+            .get(&Semantic::FeatureIds(set))
+            .and_then(|accessor| match accessor.data_type() {
+                DataType::U8 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
+                    .map(ReadFeatureIds::U8),
+                DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
+                    .map(ReadFeatureIds::U16),
+                DataType::U32 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
+                    .map(ReadFeatureIds::U32),
+                DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
+                    .map(ReadFeatureIds::F32),
+                _ => None,
+            })
+    }
+
+    /// Reads an application-specific vertex attribute such as `_BATCHID`.
+    ///
+    /// The leading underscore of `name` is optional. The number of components
+    /// per element is given by the dimensions of the attribute's accessor.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn read_custom(&self, name: &str) -> Option<util::AttributeData> {
+        use self::util::AttributeData;
+        use accessor::{DataType, Dimensions};
+
+        let name = name.strip_prefix('_').unwrap_or(name);
+        let accessor = self.primitive.get(&Semantic::Extras(name.to_string()))?;
+        let bytes = accessor::util::resolved_bytes(&accessor, self.get_buffer_data.clone())?;
+
+        // The element size includes the padding of matrix columns.
+        let component_size = accessor.data_type().size();
+        let columns = match accessor.dimensions() {
+            Dimensions::Mat2 => 2,
+            Dimensions::Mat3 => 3,
+            Dimensions::Mat4 => 4,
+            _ => 1,
+        };
+        let rows = accessor.dimensions().multiplicity() / columns;
+        let column_stride = accessor.size() / columns;
+        let components: Vec<&[u8]> = bytes
+            .chunks_exact(column_stride)
+            .flat_map(|column| column[..rows * component_size].chunks_exact(component_size))
+            .collect();
+
+        let data = match accessor.data_type() {
+            DataType::I8 => AttributeData::I8(components.iter().map(|c| c[0] as i8).collect()),
+            DataType::U8 => AttributeData::U8(components.iter().map(|c| c[0]).collect()),
+            DataType::I16 => AttributeData::I16(
+                components
+                    .iter()
+                    .map(|c| i16::from_le_bytes([c[0], c[1]]))
+                    .collect(),
+            ),
+            DataType::U16 => AttributeData::U16(
+                components
+                    .iter()
+                    .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                    .collect(),
+            ),
+            DataType::U32 => AttributeData::U32(
+                components
+                    .iter()
+                    .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+                    .collect(),
+            ),
+            DataType::F32 => AttributeData::F32(
+                components
+                    .iter()
+                    .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+                    .collect(),
+            ),
+        };
+        Some(data)
+    }
+
+    /// Returns the vertex draw sequence of the primitive.
+    ///
+    /// This is the index data for indexed primitives, and the sequence of all
+    /// vertices otherwise.
+    fn vertex_sequence(&self) -> Option<Vec<u32>> {
+        match self.read_indices() {
+            Some(indices) => Some(indices.into_u32().collect()),
+            None if self.primitive.indices().is_some() => None,
+            None => self
+                .primitive
+                .get(&Semantic::Positions)
+                .map(|accessor| (0..accessor.count() as u32).collect()),
+        }
+    }
+
+    /// Visits each vertex index of the primitive alongside the position it
+    /// references.
+    ///
+    /// Non-indexed primitives yield their vertices in order. Indices that
+    /// reference no position are skipped.
+    pub fn indexed_positions(&self) -> Option<impl Iterator<Item = (u32, [f32; 3])>> {
+        let positions: Vec<[f32; 3]> = self.read_positions()?.collect();
+        let sequence = self.vertex_sequence()?;
+        Some(
+            sequence
+                .into_iter()
+                .filter_map(move |index| Some((index, *positions.get(index as usize)?))),
+        )
+    }
+
+    /// Returns the vertex positions of each triangle of the primitive.
+    ///
+    /// Indices are resolved and triangle strips and fans are expanded into
+    /// individual triangles. Returns `None` for point and line topologies, or
+    /// if the position data is unavailable.
+    pub fn triangles(&self) -> Option<Vec<[[f32; 3]; 3]>> {
+        let positions: Vec<[f32; 3]> = self.read_positions()?.collect();
+        let sequence = self.vertex_sequence()?;
+        util::topology::triangles(self.primitive.mode(), &sequence)?
+            .into_iter()
+            .map(|[a, b, c]| {
+                Some([
+                    *positions.get(a as usize)?,
+                    *positions.get(b as usize)?,
+                    *positions.get(c as usize)?,
+                ])
+            })
+            .collect()
+    }
+
+    /// Returns the vertex positions of each line segment of the primitive.
+    ///
+    /// Indices are resolved and line strips and loops are expanded into
+    /// individual segments. Returns `None` for point and triangle topologies,
+    /// or if the position data is unavailable.
+    pub fn line_segments(&self) -> Option<Vec<[[f32; 3]; 2]>> {
+        let positions: Vec<[f32; 3]> = self.read_positions()?.collect();
+        let sequence = self.vertex_sequence()?;
+        util::topology::lines(self.primitive.mode(), &sequence)?
+            .into_iter()
+            .map(|[a, b]| Some([*positions.get(a as usize)?, *positions.get(b as usize)?]))
+            .collect()
+    }
+
+    /// Returns the geometric normal of each triangle of the primitive, for
+    /// flat shading or collision response.
+    ///
+    /// Triangles are numbered as in [`Reader::triangles`]. Returns `None`
+    /// under the same conditions. See [`util::generate::face_normals`].
+    pub fn face_normals(&self) -> Option<Vec<[f32; 3]>> {
+        let triangles = self.triangles()?;
+        Some(util::generate::face_normals(&triangles))
+    }
+
+    /// Builds a bounding volume hierarchy over the triangles of the
+    /// primitive, for use in ray queries.
+    ///
+    /// Triangles are numbered as in [`Reader::triangles`]. Returns `None`
+    /// under the same conditions.
+    pub fn build_bvh(&self) -> Option<util::bvh::Bvh> {
+        self.triangles().map(util::bvh::Bvh::new)
+    }
+
+    /// Scans the vertex positions, and optionally the vertex normals, for NaN
+    /// or infinite components.
+    ///
+    /// Returns the semantic and index of the first offending vertex. Positions
+    /// are scanned before normals. Attributes whose data is unavailable are
+    /// not checked.
+    pub fn first_non_finite_vertex(&self, check_normals: bool) -> Option<(Semantic, u32)> {
+        let first_non_finite = |mut iter: util::ReadPositions<'s>| {
+            iter.position(|vertex| !vertex.iter().all(|component| component.is_finite()))
+                .map(|index| index as u32)
+        };
+        if let Some(index) = self.read_positions().and_then(first_non_finite) {
+            return Some((Semantic::Positions, index));
+        }
+        if check_normals {
+            if let Some(index) = self.read_normals().and_then(first_non_finite) {
+                return Some((Semantic::Normals, index));
+            }
+        }
+        None
+    }
+
+    /// Returns the indices of the vertices influenced by more than
+    /// `max_influences` joints, counting non-zero weights across all
+    /// `WEIGHTS_n` sets.
+    ///
+    /// This is useful for renderers that support a limited number of joint
+    /// influences per vertex.
+    pub fn vertices_exceeding_influences(&self, max_influences: usize) -> Vec<u32> {
+        let mut influences: Vec<usize> = Vec::new();
+        let mut set = 0;
+        while let Some(weights) = self.read_weights(set) {
+            for (vertex, weights) in weights.into_f32().enumerate() {
+                if vertex == influences.len() {
+                    influences.push(0);
+                }
+                influences[vertex] += weights.iter().filter(|&&weight| weight != 0.0).count();
+            }
+            set += 1;
+        }
+        influences
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, count)| count > max_influences)
+            .map(|(vertex, _)| vertex as u32)
+            .collect()
+    }
+
+    /// Splits the primitive into pieces of at most 65535 vertices each, so that
+    /// every piece can be drawn with `u16` indices.
+    ///
+    /// Strips, loops, and fans are expanded, so the pieces use the `Points`,
+    /// `Lines`, or `Triangles` mode corresponding to the primitive's mode.
+    /// Each piece is indexed, with all indices less than 65535 as the maximum
+    /// `u16` value is reserved for primitive restart.
+    ///
+    /// The pieces are [`SoaMesh`](util::soa::SoaMesh)es, which only hold the
+    /// attribute sets read by [`Reader::to_soa`]. Returns `None` rather than
+    /// dropping data if the primitive has other attributes, such as
+    /// `TEXCOORD_2`, `COLOR_1`, `JOINTS_1`, or custom attributes, or has morph
+    /// targets. Also returns `None` if the position data is unavailable.
+    pub fn split_for_u16(&self) -> Option<Vec<util::soa::SoaMesh>> {
+        const MAX_VERTICES: usize = u16::MAX as usize;
+
+        let representable = self.primitive.attributes().all(|(semantic, _)| {
+            matches!(
+                semantic,
+                Semantic::Positions
+                    | Semantic::Normals
+                    | Semantic::Tangents
+                    | Semantic::TexCoords(0..=1)
+                    | Semantic::Colors(0)
+                    | Semantic::Joints(0)
+                    | Semantic::Weights(0)
+            )
+        });
+        if !representable || self.primitive.morph_targets().next().is_some() {
+            return None;
+        }
+
+        let soa = self.to_soa();
+        let sequence = self.vertex_sequence()?;
+        let mode = self.primitive.mode();
+        let (group_size, groups) = match mode {
+            Mode::Points => (1, sequence),
+            Mode::Lines | Mode::LineStrip | Mode::LineLoop => {
+                (2, util::topology::lines(mode, &sequence)?.concat())
+            }
+            _ => (3, util::topology::triangles(mode, &sequence)?.concat()),
+        };
+
+        let mut pieces = Vec::new();
+        let mut piece = util::soa::SoaMesh::default();
+        let mut remap: HashMap<u32, u32> = HashMap::new();
+        for group in groups.chunks_exact(group_size) {
+            let unseen = group
+                .iter()
+                .filter(|index| !remap.contains_key(index))
+                .count();
+            if remap.len() + unseen > MAX_VERTICES {
+                pieces.push(std::mem::take(&mut piece));
+                remap.clear();
+            }
+            for &index in group {
+                let new = match remap.get(&index) {
+                    Some(&new) => new,
+                    None => {
+                        let new = remap.len() as u32;
+                        piece.push_vertex(&soa.vertex(index as usize)?);
+                        remap.insert(index, new);
+                        new
+                    }
+                };
+                piece.indices.push(new);
+            }
+        }
+        if !piece.indices.is_empty() {
+            pieces.push(piece);
+        }
+        Some(pieces)
+    }
+
+    /// Reads the vertex attributes and indices of the primitive, generating
+    /// normals and tangents where the primitive does not provide them.
+    ///
+    /// Missing normals are generated first by smoothing face normals, and
+    /// missing tangents are then generated from the normals and the first set
+    /// of texture co-ordinates. Tangents are left empty if there are no
+    /// texture co-ordinates. Returns `None` for point and line topologies, or
+    /// if the position data is unavailable.
+    pub fn with_generated_normals_and_tangents(&self) -> Option<util::soa::SoaMesh> {
+        let mut soa = self.to_soa();
+        let sequence = self.vertex_sequence()?;
+        let triangles = util::topology::triangles(self.primitive.mode(), &sequence)?;
+        let vertex_count = soa.positions.len() as u32;
+        if triangles
+            .iter()
+            .flatten()
+            .any(|&index| index >= vertex_count)
+        {
+            return None;
+        }
+
+        if soa.normals.is_empty() {
+            soa.normals = util::generate::smooth_normals(&soa.positions, &triangles);
+        }
+        if soa.tangents.is_empty() && soa.tex_coords_0.len() == soa.positions.len() {
+            soa.tangents = util::generate::tangents(
+                &soa.positions,
+                &soa.normals,
+                &soa.tex_coords_0,
+                &triangles,
+            );
+        }
+        Some(soa)
+    }
+
+    /// Reads the vertex attributes and indices of the primitive into a
+    /// struct-of-arrays container.
+    pub fn to_soa(&self) -> util::soa::SoaMesh {
+        util::soa::SoaMesh {
+            positions: self
+                .read_positions()
+                .map_or_else(Vec::new, Iterator::collect),
+            normals: self.read_normals().map_or_else(Vec::new, Iterator::collect),
+            tangents: self
+                .read_tangents()
+                .map_or_else(Vec::new, Iterator::collect),
+            tex_coords_0: self
+                .read_tex_coords(0)
+                .map_or_else(Vec::new, |iter| iter.into_f32().collect()),
+            tex_coords_1: self
+                .read_tex_coords(1)
+                .map_or_else(Vec::new, |iter| iter.into_f32().collect()),
+            colors_0: self
+                .read_colors(0)
+                .map_or_else(Vec::new, |iter| iter.into_rgba_f32().collect()),
+            joints_0: self
+                .read_joints(0)
+                .map_or_else(Vec::new, |iter| iter.into_u16().collect()),
+            weights_0: self
+                .read_weights(0)
+                .map_or_else(Vec::new, |iter| iter.into_f32().collect()),
+            indices: self
+                .read_indices()
+                .map_or_else(Vec::new, |iter| iter.into_u32().collect()),
+        }
+    }
+
+    /// Writes the triangles of the primitive as the `v`, `vt`, `vn`, and `f`
+    /// lines of a Wavefront OBJ file, for example to inspect the geometry.
+    ///
+    /// Strips and fans are expanded to triangle lists first. Returns `None`
+    /// for point and line topologies, or if the primitive refers to vertices
+    /// out of range. See [`util::export::obj`].
+    pub fn to_obj_fragment(&self) -> Option<String> {
+        let mut soa = self.to_soa();
+        let triangles = util::topology::triangles(self.primitive.mode(), &self.vertex_sequence()?)?;
+        soa.indices = triangles.iter().flatten().copied().collect();
+        if soa
+            .indices
+            .iter()
+            .any(|&index| index as usize >= soa.positions.len())
+        {
+            return None;
+        }
+        Some(util::export::obj(&soa))
+    }
+
+    /// Reads a vertex attribute in parallel, or returns an empty vector if it
+    /// is absent or cannot be read.
+    #[cfg(feature = "rayon")]
+    fn read_attribute_par<T: accessor::Item + Send>(&self, semantic: Semantic) -> Vec<T> {
+        self.primitive
+            .get(&semantic)
+            .and_then(|accessor| accessor::util::read_par(accessor, self.get_buffer_data.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Reads the vertex attributes and indices of the primitive into a
+    /// struct-of-arrays container, decoding the arrays in parallel.
+    ///
+    /// The result is identical to [`Reader::to_soa`].
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn to_soa_par(&self) -> util::soa::SoaMesh
+    where
+        F: Sync,
+    {
+        let mut soa = util::soa::SoaMesh::default();
+        let util::soa::SoaMesh {
+            positions,
+            normals,
+            tangents,
+            tex_coords_0,
+            tex_coords_1,
+            colors_0,
+            joints_0,
+            weights_0,
+            indices,
+        } = &mut soa;
+        rayon::scope(|scope| {
+            scope.spawn(|_| *positions = self.read_attribute_par(Semantic::Positions));
+            scope.spawn(|_| *normals = self.read_attribute_par(Semantic::Normals));
+            scope.spawn(|_| *tangents = self.read_attribute_par(Semantic::Tangents));
+            scope.spawn(|_| {
+                *tex_coords_0 = self
+                    .read_tex_coords(0)
+                    .map_or_else(Vec::new, |iter| iter.into_f32().collect())
+            });
+            scope.spawn(|_| {
+                *tex_coords_1 = self
+                    .read_tex_coords(1)
+                    .map_or_else(Vec::new, |iter| iter.into_f32().collect())
+            });
+            scope.spawn(|_| {
+                *colors_0 = self
+                    .read_colors(0)
+                    .map_or_else(Vec::new, |iter| iter.into_rgba_f32().collect())
+            });
+            scope.spawn(|_| {
+                *joints_0 = self
+                    .read_joints(0)
+                    .map_or_else(Vec::new, |iter| iter.into_u16().collect())
+            });
+            scope.spawn(|_| {
+                *weights_0 = self
+                    .read_weights(0)
+                    .map_or_else(Vec::new, |iter| iter.into_f32().collect())
+            });
+            scope.spawn(|_| {
+                *indices = self
+                    .read_indices()
+                    .map_or_else(Vec::new, |iter| iter.into_u32().collect())
+            });
+        });
+        soa
+    }
+
+    /// Returns a stable hash of the primitive's content, for use as a cache
+    /// key.
+    ///
+    /// The hash covers the mode, the resolved bytes of each vertex attribute
+    /// in order of semantic name, and the indices widened to `u32`. It is
+    /// therefore independent of strides, sparse storage, index type, and the
+    /// order in which attributes are declared, but depends on vertex order.
+    /// Attribute data that cannot be resolved contributes no bytes. Morph
+    /// targets and the material are not considered.
+    ///
+    /// The algorithm is 64-bit FNV-1a, so hashes are stable across platforms
+    /// and releases. Each attribute is encoded as its semantic name followed
+    /// by its bytes, and each sequence of bytes is prefixed with its length
+    /// as a little-endian `u64`.
+    pub fn content_hash(&self) -> u64 {
+        let mut hash = Fnv1a::new();
+        hash.write(&self.primitive.mode().as_gl_enum().to_le_bytes());
+        self.hash_attributes(&mut hash);
+        if let Some(indices) = self.read_indices() {
+            hash.write(&(indices.clone().into_u32().len() as u64).to_le_bytes());
+            for index in indices.into_u32() {
+                hash.write(&index.to_le_bytes());
+            }
+        }
+        hash.finish()
+    }
+
+    /// Returns a checksum of the buffer data of the primitive, for detecting
+    /// corruption.
+    ///
+    /// Unlike [`Reader::content_hash`], the checksum covers the data exactly
+    /// as the accessors describe it: the resolved bytes of each vertex
+    /// attribute in order of semantic name, of the indices in their stored
+    /// type, and of the attributes of each morph target. Strides and sparse
+    /// storage do not contribute. Data that cannot be resolved contributes no
+    /// bytes.
+    ///
+    /// The algorithm and encoding are those of [`Reader::content_hash`].
+    pub fn data_checksum(&self) -> u64 {
+        self.hash_data().0
+    }
+
+    /// Compares [`Reader::data_checksum`] against the checksum stored in
+    /// `extras.checksum` of the primitive.
+    ///
+    /// Returns `None` if no checksum is stored. Data that cannot be resolved
+    /// is reported as `ChecksumStatus::Unresolved` rather than compared.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn verify_checksum(&self) -> Option<ChecksumStatus> {
+        let expected = self.primitive.json.checksum()?;
+        Some(match self.hash_data() {
+            (_, false) => ChecksumStatus::Unresolved,
+            (checksum, true) if checksum == expected => ChecksumStatus::Match,
+            (_, true) => ChecksumStatus::Mismatch,
+        })
+    }
+
+    /// Returns [`Reader::data_checksum`] and whether all of the data it
+    /// covers could be resolved.
+    fn hash_data(&self) -> (u64, bool) {
+        let mut hash = Fnv1a::new();
+        let mut resolved = self.hash_attributes(&mut hash);
+        if let Some(indices) = self.primitive.indices() {
+            resolved &= self.hash_accessor(&mut hash, "indices", Some(indices));
+        }
+        for target in self.primitive.morph_targets() {
+            resolved &= self.hash_accessor(&mut hash, "targets.POSITION", target.positions());
+            resolved &= self.hash_accessor(&mut hash, "targets.NORMAL", target.normals());
+            resolved &= self.hash_accessor(&mut hash, "targets.TANGENT", target.tangents());
+        }
+        (hash.finish(), resolved)
+    }
+
+    /// Hashes each vertex attribute in order of semantic name, returning
+    /// whether the data of every attribute could be resolved.
+    fn hash_attributes(&self, hash: &mut Fnv1a) -> bool {
+        let mut attributes: Vec<(String, Accessor<'a>)> = self
+            .primitive
+            .attributes()
+            .map(|(semantic, accessor)| (semantic.to_string(), accessor))
+            .collect();
+        attributes.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut resolved = true;
+        for (name, accessor) in attributes {
+            resolved &= self.hash_accessor(hash, &name, Some(accessor));
+        }
+        resolved
+    }
+
+    /// Hashes `label` followed by the resolved bytes of `accessor`, if any,
+    /// returning `false` if the accessor's data could not be resolved.
+    fn hash_accessor(&self, hash: &mut Fnv1a, label: &str, accessor: Option<Accessor<'a>>) -> bool {
+        let bytes = accessor
+            .as_ref()
+            .map(|accessor| accessor::util::resolved_bytes(accessor, self.get_buffer_data.clone()));
+        let resolved = !matches!(bytes, Some(None));
+        let bytes = bytes.flatten().unwrap_or_default();
+        hash.write(&(label.len() as u64).to_le_bytes());
+        hash.write(label.as_bytes());
+        hash.write(&(bytes.len() as u64).to_le_bytes());
+        hash.write(&bytes);
+        resolved
+    }
+
+    /// Reads the attributes of each vertex of the primitive in buffer order.
+    pub fn read_vertices(&self) -> Vec<util::vertex::Vertex> {
+        self.to_soa().vertices().collect()
+    }
+
+    /// Visits the attributes of each vertex in the vertex draw sequence, that
+    /// is in index buffer order for indexed primitives.
+    ///
+    /// Unlike [`Reader::read_vertices`], which visits vertices in buffer
+    /// order, vertices referenced by several indices are visited once per
+    /// index. Indices that reference no vertex are skipped, and strips and
+    /// fans are not expanded into triangle lists.
+    pub fn vertices_indexed(&self) -> impl Iterator<Item = util::vertex::Vertex> {
+        let soa = self.to_soa();
+        let sequence = self.vertex_sequence().unwrap_or_default();
+        sequence
+            .into_iter()
+            .filter_map(move |index| soa.vertex(index as usize))
+    }
+
+    /// Measures how well the vertex draw sequence suits a post-transform
+    /// vertex cache of `cache_size` entries, as the average cache miss ratio.
+    ///
+    /// Strips and fans are expanded to triangle lists first. Returns 0.0 if
+    /// the primitive has no triangles. See
+    /// [`util::optimize::average_cache_miss_ratio`].
+    pub fn vertex_cache_score(&self, cache_size: usize) -> f32 {
+        let triangles = self
+            .vertex_sequence()
+            .and_then(|sequence| util::topology::triangles(self.primitive.mode(), &sequence))
+            .unwrap_or_default();
+        let indices: Vec<u32> = triangles.iter().flatten().copied().collect();
+        util::optimize::average_cache_miss_ratio(&indices, cache_size)
+    }
+
+    /// Reads the vertices of the primitive reordered by first use in the
+    /// vertex draw sequence, returning the vertices and new indices.
+    ///
+    /// The indices are used with the same mode and describe the same
+    /// geometry; vertices that are never drawn are omitted. Returns empty
+    /// buffers if the primitive has no positions or refers to vertices out
+    /// of range. See [`util::optimize::optimize_vertex_fetch`].
+    pub fn optimize_vertex_fetch(&self) -> (Vec<util::vertex::Vertex>, Vec<u32>) {
+        let soa = self.to_soa();
+        let mut indices = match self.vertex_sequence() {
+            Some(sequence) => sequence,
+            None => return (Vec::new(), Vec::new()),
+        };
+        let order = util::optimize::optimize_vertex_fetch(&mut indices);
+        let vertices: Option<Vec<_>> = order
+            .into_iter()
+            .map(|index| soa.vertex(index as usize))
+            .collect();
+        match vertices {
+            Some(vertices) => (vertices, indices),
+            None => (Vec::new(), Vec::new()),
+        }
+    }
+
+    /// Simplifies the triangles of the primitive to about `target_ratio` of
+    /// their original number, returning the remaining vertices and the indices
+    /// of a triangle list.
+    ///
+    /// Strips and fans are expanded to triangle lists first, and the vertices
+    /// are renumbered in order of first use. Returns empty buffers if the
+    /// primitive has no triangles or refers to vertices out of range. See
+    /// [`util::simplify::simplify`].
+    pub fn simplify(&self, target_ratio: f32) -> (Vec<util::vertex::Vertex>, Vec<u32>) {
+        let soa = self.to_soa();
+        let triangles = self
+            .vertex_sequence()
+            .and_then(|sequence| util::topology::triangles(self.primitive.mode(), &sequence))
+            .unwrap_or_default();
+        let indices: Vec<u32> = triangles.iter().flatten().copied().collect();
+        if indices
+            .iter()
+            .any(|&index| index as usize >= soa.positions.len())
+        {
+            return (Vec::new(), Vec::new());
+        }
+        let mut indices = util::simplify::simplify(&soa.positions, &indices, target_ratio);
+        let order = util::optimize::optimize_vertex_fetch(&mut indices);
+        let vertices: Option<Vec<_>> = order
+            .into_iter()
+            .map(|index| soa.vertex(index as usize))
+            .collect();
+        match vertices {
+            Some(vertices) => (vertices, indices),
+            None => (Vec::new(), Vec::new()),
+        }
+    }
+
+    /// Builds an index buffer for a non-indexed primitive by merging vertices
+    /// whose attributes all lie within `epsilon` of each other.
+    ///
+    /// This is [`Reader::weld_vertices`] under a name that states the intent;
+    /// indexed primitives are welded in the same way.
+    pub fn to_indexed(&self, epsilon: f32) -> (Vec<util::vertex::Vertex>, Vec<u32>) {
+        self.weld_vertices(epsilon)
+    }
+
+    /// Merges vertices whose attributes all lie within `epsilon` of each
+    /// other, returning the merged vertices and new indices.
+    ///
+    /// The indices replace the vertex draw sequence of the primitive, so they
+    /// are used with the same mode. Candidate vertices are found with a
+    /// spatial hash over the positions. Returns empty buffers if the
+    /// primitive has no positions.
+    pub fn weld_vertices(&self, epsilon: f32) -> (Vec<util::vertex::Vertex>, Vec<u32>) {
+        let soa = self.to_soa();
+        let sequence = match self.vertex_sequence() {
+            Some(sequence) if !soa.positions.is_empty() => sequence,
+            _ => return (Vec::new(), Vec::new()),
+        };
+
+        let cell_size = epsilon.max(f32::EPSILON);
+        let cell = |p: [f32; 3]| {
+            [
+                (p[0] / cell_size).floor() as i64,
+                (p[1] / cell_size).floor() as i64,
+                (p[2] / cell_size).floor() as i64,
+            ]
+        };
+        let mut cells: HashMap<[i64; 3], Vec<u32>> = HashMap::new();
+        let mut vertices: Vec<util::vertex::Vertex> = Vec::new();
+        let mut remap: HashMap<u32, u32> = HashMap::new();
+        let mut indices = Vec::with_capacity(sequence.len());
+        for index in sequence {
+            if let Some(&welded) = remap.get(&index) {
+                indices.push(welded);
+                continue;
+            }
+            let vertex = match soa.vertex(index as usize) {
+                Some(vertex) => vertex,
+                None => continue,
+            };
+            let [x, y, z] = cell(vertex.position);
+            let mut found = None;
+            'search: for dx in -1..=1 {
+                for dy in -1..=1 {
+                    for dz in -1..=1 {
+                        let candidates = cells.get(&[x + dx, y + dy, z + dz]);
+                        for &candidate in candidates.into_iter().flatten() {
+                            if vertices[candidate as usize].approx_eq(&vertex, epsilon) {
+                                found = Some(candidate);
+                                break 'search;
+                            }
+                        }
+                    }
+                }
+            }
+            let welded = found.unwrap_or_else(|| {
+                vertices.push(vertex);
+                let welded = vertices.len() as u32 - 1;
+                cells.entry([x, y, z]).or_default().push(welded);
+                welded
+            });
+            remap.insert(index, welded);
+            indices.push(welded);
+        }
+        (vertices, indices)
+    }
+
+    /// Checks that the sparse indices of every accessor used by the primitive
+    /// are strictly increasing and less than the accessor's element count.
+    ///
+    /// This covers the vertex attributes, the index accessor, and the morph
+    /// targets. Sparse indices whose data is unavailable are not checked.
+    pub fn validate_sparse_indices(&self) -> crate::Result<()> {
+        let json = self.primitive.json;
+        let mut used: Vec<usize> = json
+            .attributes
+            .values()
+            .chain(json.indices.iter())
+            .chain(json.targets.iter().flatten().flat_map(|target| {
+                target
+                    .positions
+                    .iter()
+                    .chain(target.normals.iter())
+                    .chain(target.tangents.iter())
+            }))
+            .map(|index| index.value())
+            .collect();
+        used.sort_unstable();
+        used.dedup();
+
+        let mut errors = Vec::new();
+        for accessor in used
+            .into_iter()
+            .filter_map(|index| self.primitive.mesh.document.accessors().nth(index))
+        {
+            let sparse = match accessor.sparse() {
+                Some(sparse) => sparse,
+                None => continue,
+            };
+            let indices =
+                match accessor::util::sparse_indices(&sparse, self.get_buffer_data.clone()) {
+                    Some(indices) => indices,
+                    None => continue,
+                };
+            let increasing = indices.windows(2).all(|pair| pair[0] < pair[1]);
+            let bounded = indices
+                .iter()
+                .all(|&index| (index as usize) < accessor.count());
+            if !increasing || !bounded {
+                let index = accessor.index();
+                errors.push((
+                    json::Path::new()
+                        .field("accessors")
+                        .index(index)
+                        .field("sparse")
+                        .field("indices"),
+                    json::validation::Error::Invalid,
+                ));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::Error::Validation(errors))
+        }
+    }
+
     /// Visits the morph targets of the primitive.
     pub fn read_morph_targets(&self) -> util::ReadMorphTargets<'a, 's, F> {
         util::ReadMorphTargets {
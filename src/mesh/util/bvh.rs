@@ -0,0 +1,224 @@
+use super::generate::{cross, dot, sub};
+use crate::mesh::{BoundingBox, Bounds};
+use std::cmp::Ordering;
+
+/// The maximum number of triangles stored in a leaf node.
+const LEAF_SIZE: usize = 4;
+
+/// The nearest intersection of a ray with a triangle of a [`Bvh`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hit {
+    /// The index of the triangle that was hit.
+    pub triangle: usize,
+
+    /// The barycentric co-ordinates of the hit point with respect to the
+    /// three vertices of the triangle.
+    pub barycentric: [f32; 3],
+
+    /// The distance to the hit point along the ray, in multiples of the
+    /// length of the ray direction.
+    pub t: f32,
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    /// The bounds of every triangle below this node.
+    bounds: BoundingBox,
+
+    /// The range of `Bvh::order` covered by this node.
+    start: usize,
+    end: usize,
+
+    /// The indices of the child nodes, or `None` for a leaf node.
+    children: Option<[usize; 2]>,
+}
+
+/// A bounding volume hierarchy over the triangles of a primitive, for use in
+/// ray queries such as picking.
+///
+/// The hierarchy is built by recursively splitting the triangles at the
+/// median of their centroids along the longest axis.
+#[derive(Clone, Debug)]
+pub struct Bvh {
+    triangles: Vec<[[f32; 3]; 3]>,
+    order: Vec<usize>,
+    nodes: Vec<Node>,
+}
+
+fn centroid(triangle: &[[f32; 3]; 3]) -> [f32; 3] {
+    let [a, b, c] = triangle;
+    [
+        (a[0] + b[0] + c[0]) / 3.0,
+        (a[1] + b[1] + c[1]) / 3.0,
+        (a[2] + b[2] + c[2]) / 3.0,
+    ]
+}
+
+fn extend(bounds: &mut BoundingBox, point: [f32; 3]) {
+    let components = bounds.min.iter_mut().zip(bounds.max.iter_mut());
+    for ((min, max), &x) in components.zip(point.iter()) {
+        *min = min.min(x);
+        *max = max.max(x);
+    }
+}
+
+fn empty_bounds() -> BoundingBox {
+    Bounds {
+        min: [f32::INFINITY; 3],
+        max: [f32::NEG_INFINITY; 3],
+    }
+}
+
+/// Returns whether the ray enters `bounds` before the distance `limit`.
+fn hits_bounds(bounds: &BoundingBox, origin: [f32; 3], inverse: [f32; 3], limit: f32) -> bool {
+    let mut near = 0.0f32;
+    let mut far = limit;
+    for i in 0..3 {
+        let t0 = (bounds.min[i] - origin[i]) * inverse[i];
+        let t1 = (bounds.max[i] - origin[i]) * inverse[i];
+        near = near.max(t0.min(t1));
+        far = far.min(t0.max(t1));
+    }
+    near <= far
+}
+
+/// Intersects a ray with a triangle using the Möller–Trumbore algorithm,
+/// returning the distance and the barycentric co-ordinates of the second and
+/// third vertices.
+fn intersect_triangle(
+    triangle: &[[f32; 3]; 3],
+    origin: [f32; 3],
+    direction: [f32; 3],
+) -> Option<(f32, f32, f32)> {
+    let [a, b, c] = *triangle;
+    let e1 = sub(b, a);
+    let e2 = sub(c, a);
+    let p = cross(direction, e2);
+    let determinant = dot(e1, p);
+    if determinant == 0.0 {
+        return None;
+    }
+    let inverse = 1.0 / determinant;
+    let s = sub(origin, a);
+    let u = dot(s, p) * inverse;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = cross(s, e1);
+    let v = dot(direction, q) * inverse;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = dot(e2, q) * inverse;
+    if t > 0.0 {
+        Some((t, u, v))
+    } else {
+        None
+    }
+}
+
+impl Bvh {
+    /// Builds a hierarchy over the given triangles.
+    ///
+    /// The triangles are referred to by their index in `triangles`, as
+    /// returned by [`crate::mesh::Reader::triangles`].
+    pub fn new(triangles: Vec<[[f32; 3]; 3]>) -> Self {
+        let mut bvh = Bvh {
+            order: (0..triangles.len()).collect(),
+            triangles,
+            nodes: Vec::new(),
+        };
+        if !bvh.triangles.is_empty() {
+            bvh.build(0, bvh.triangles.len());
+        }
+        bvh
+    }
+
+    /// Adds the node covering `order[start..end]` and its descendants,
+    /// returning the index of the node.
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let mut bounds = empty_bounds();
+        let mut centroids = empty_bounds();
+        for &triangle in &self.order[start..end] {
+            for &vertex in &self.triangles[triangle] {
+                extend(&mut bounds, vertex);
+            }
+            extend(&mut centroids, centroid(&self.triangles[triangle]));
+        }
+
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            bounds,
+            start,
+            end,
+            children: None,
+        });
+
+        let extent = sub(centroids.max, centroids.min);
+        let axis = if extent[0] >= extent[1] && extent[0] >= extent[2] {
+            0
+        } else if extent[1] >= extent[2] {
+            1
+        } else {
+            2
+        };
+        // Triangles with coincident centroids cannot be split further.
+        if end - start > LEAF_SIZE && extent[axis] > 0.0 {
+            let middle = (start + end) / 2;
+            let triangles = &self.triangles;
+            self.order[start..end].select_nth_unstable_by(middle - start, |&a, &b| {
+                centroid(&triangles[a])[axis]
+                    .partial_cmp(&centroid(&triangles[b])[axis])
+                    .unwrap_or(Ordering::Equal)
+            });
+            let left = self.build(start, middle);
+            let right = self.build(middle, end);
+            self.nodes[index].children = Some([left, right]);
+        }
+        index
+    }
+
+    /// Returns the bounds of every triangle, or `None` if there are no
+    /// triangles.
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        self.nodes.first().map(|node| node.bounds.clone())
+    }
+
+    /// Returns the nearest intersection of the ray from `origin` in
+    /// `direction` with the front or back face of any triangle.
+    ///
+    /// Intersections at or behind the origin are ignored.
+    pub fn ray_intersect(&self, origin: [f32; 3], direction: [f32; 3]) -> Option<Hit> {
+        let inverse = [1.0 / direction[0], 1.0 / direction[1], 1.0 / direction[2]];
+        let mut nearest: Option<Hit> = None;
+        let mut stack = if self.nodes.is_empty() {
+            Vec::new()
+        } else {
+            vec![0]
+        };
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let limit = nearest.map(|hit| hit.t).unwrap_or(f32::INFINITY);
+            if !hits_bounds(&node.bounds, origin, inverse, limit) {
+                continue;
+            }
+            if let Some(children) = node.children {
+                stack.extend_from_slice(&children);
+                continue;
+            }
+            for &triangle in &self.order[node.start..node.end] {
+                let hit = intersect_triangle(&self.triangles[triangle], origin, direction);
+                if let Some((t, u, v)) = hit {
+                    if t < nearest.map(|hit| hit.t).unwrap_or(f32::INFINITY) {
+                        nearest = Some(Hit {
+                            triangle,
+                            barycentric: [1.0 - u - v, u, v],
+                            t,
+                        });
+                    }
+                }
+            }
+        }
+        nearest
+    }
+}
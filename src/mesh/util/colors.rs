@@ -32,6 +32,30 @@ pub struct RgbaU16;
 #[derive(Clone, Debug)]
 pub struct RgbaF32;
 
+/// How to combine two RGBA f32 vertex colors, such as `COLOR_0` and `COLOR_1`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ColorBlend {
+    /// Multiplies the colors component-wise.
+    Multiply,
+
+    /// Adds the colors component-wise, clamping each component to `1.0`.
+    Add,
+}
+
+impl ColorBlend {
+    /// Combines two RGBA f32 colors.
+    pub fn apply(self, a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        let mut color = [0.0; 4];
+        for ((c, a), b) in color.iter_mut().zip(a.iter()).zip(b.iter()) {
+            *c = match self {
+                ColorBlend::Multiply => a * b,
+                ColorBlend::Add => (a + b).min(1.0),
+            };
+        }
+        color
+    }
+}
+
 trait ColorChannel {
     fn max_color() -> Self;
 }
@@ -168,6 +192,49 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
     }
 }
 
+/// Casting iterator for `Colors` into RGBA `f32` colors, giving RGB colors a
+/// configurable alpha instead of `1.0`.
+#[derive(Clone, Debug)]
+pub struct WithAlpha<'a> {
+    iter: CastingIter<'a, RgbaF32>,
+    /// The alpha of RGB colors, or `None` if the colors have alpha.
+    alpha: Option<f32>,
+}
+
+impl<'a> WithAlpha<'a> {
+    pub(crate) fn new(iter: ReadColors<'a>, alpha: f32) -> Self {
+        let is_rgb = matches!(
+            iter,
+            ReadColors::RgbU8(_) | ReadColors::RgbU16(_) | ReadColors::RgbF32(_)
+        );
+        WithAlpha {
+            iter: CastingIter::new(iter),
+            alpha: if is_rgb { Some(alpha) } else { None },
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for WithAlpha<'a> {}
+impl<'a> Iterator for WithAlpha<'a> {
+    type Item = [f32; 4];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let alpha = self.alpha;
+        self.iter.next().map(|mut color| {
+            if let Some(alpha) = alpha {
+                color[3] = alpha;
+            }
+            color
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 impl Cast for RgbU8 {
     type Output = [u8; 3];
 
@@ -0,0 +1,273 @@
+use super::soa::SoaMesh;
+use crate::mesh::Mode;
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::validation::Checked::Valid;
+use std::collections::HashMap;
+
+/// The glTF objects describing an exported primitive, together with the
+/// contents of the buffer they refer to.
+///
+/// The objects refer to each other by their position in these vectors, and
+/// to the buffer by index 0, so they can make up a new document directly.
+#[derive(Clone, Debug)]
+pub struct Exported {
+    /// The primitive, referring to `accessors`.
+    pub primitive: json::mesh::Primitive,
+
+    /// The accessors, one per vertex attribute followed by the indices.
+    pub accessors: Vec<json::Accessor>,
+
+    /// The buffer views, one per accessor.
+    pub views: Vec<json::buffer::View>,
+
+    /// The buffer of `bin`, without a URI.
+    pub buffer: json::Buffer,
+
+    /// The buffer contents, padded to a multiple of four bytes.
+    pub bin: Vec<u8>,
+}
+
+impl Exported {
+    /// Writes a tightly packed buffer view holding `bytes`, starting at a
+    /// multiple of four bytes.
+    fn push_view(&mut self, bytes: &[u8], target: json::buffer::Target) -> u32 {
+        let byte_offset = self.bin.len() as u32;
+        self.bin.extend_from_slice(bytes);
+        self.bin.resize((self.bin.len() + 3) & !3, 0);
+        self.views.push(json::buffer::View {
+            buffer: json::Index::new(0),
+            byte_length: bytes.len() as u32,
+            byte_offset: Some(byte_offset),
+            byte_stride: None,
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            target: Some(Valid(target)),
+        });
+        self.views.len() as u32 - 1
+    }
+
+    /// Writes an accessor and its buffer view, returning the accessor index.
+    fn push_accessor(
+        &mut self,
+        bytes: &[u8],
+        count: usize,
+        component_type: ComponentType,
+        type_: Type,
+        target: json::buffer::Target,
+    ) -> json::Index<json::Accessor> {
+        let view = self.push_view(bytes, target);
+        self.accessors.push(json::Accessor {
+            buffer_view: Some(json::Index::new(view)),
+            byte_offset: 0,
+            count: count as u32,
+            component_type: Valid(GenericComponentType(component_type)),
+            extensions: Default::default(),
+            extras: Default::default(),
+            type_: Valid(type_),
+            min: None,
+            max: None,
+            #[cfg(feature = "names")]
+            name: None,
+            normalized: false,
+            sparse: None,
+        });
+        json::Index::new(self.accessors.len() as u32 - 1)
+    }
+
+    /// Writes a floating point vertex attribute unless it is empty.
+    fn push_f32_attribute<'a, I>(&mut self, semantic: json::mesh::Semantic, type_: Type, data: I)
+    where
+        I: ExactSizeIterator<Item = &'a [f32]>,
+    {
+        let count = data.len();
+        if count == 0 {
+            return;
+        }
+        let bytes: Vec<u8> = data
+            .flat_map(|element| element.iter().flat_map(|x| x.to_le_bytes()))
+            .collect();
+        let index = self.push_accessor(
+            &bytes,
+            count,
+            ComponentType::F32,
+            type_,
+            json::buffer::Target::ArrayBuffer,
+        );
+        self.primitive.attributes.insert(Valid(semantic), index);
+    }
+}
+
+/// Writes the vertex attributes and indices of a primitive to a new buffer,
+/// with one tightly packed buffer view per accessor.
+///
+/// Empty attributes are omitted, as are empty indices. Every attribute
+/// should have one element per position. Indices are written as unsigned
+/// shorts when every index is below 65535, and as unsigned integers
+/// otherwise. The `POSITION` accessor has `min` and `max` set, as the
+/// specification requires.
+pub fn export(mesh: &SoaMesh, mode: Mode) -> Exported {
+    use json::mesh::Semantic;
+
+    let mut exported = Exported {
+        primitive: json::mesh::Primitive {
+            attributes: HashMap::new(),
+            extensions: Default::default(),
+            extras: Default::default(),
+            indices: None,
+            material: None,
+            mode: Valid(mode),
+            extension_mode: Default::default(),
+            targets: None,
+        },
+        accessors: Vec::new(),
+        views: Vec::new(),
+        buffer: json::Buffer {
+            byte_length: 0,
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+        },
+        bin: Vec::new(),
+    };
+
+    exported.push_f32_attribute(
+        Semantic::Positions,
+        Type::Vec3,
+        mesh.positions.iter().map(|p| &p[..]),
+    );
+    if let Some(first) = mesh.positions.first() {
+        let (mut min, mut max) = (*first, *first);
+        for position in &mesh.positions {
+            for i in 0..3 {
+                min[i] = min[i].min(position[i]);
+                max[i] = max[i].max(position[i]);
+            }
+        }
+        let accessor = exported.accessors.last_mut().unwrap();
+        accessor.min = Some(json::Value::from(min.to_vec()));
+        accessor.max = Some(json::Value::from(max.to_vec()));
+    }
+    exported.push_f32_attribute(
+        Semantic::Normals,
+        Type::Vec3,
+        mesh.normals.iter().map(|n| &n[..]),
+    );
+    exported.push_f32_attribute(
+        Semantic::Tangents,
+        Type::Vec4,
+        mesh.tangents.iter().map(|t| &t[..]),
+    );
+    exported.push_f32_attribute(
+        Semantic::TexCoords(0),
+        Type::Vec2,
+        mesh.tex_coords_0.iter().map(|uv| &uv[..]),
+    );
+    exported.push_f32_attribute(
+        Semantic::TexCoords(1),
+        Type::Vec2,
+        mesh.tex_coords_1.iter().map(|uv| &uv[..]),
+    );
+    exported.push_f32_attribute(
+        Semantic::Colors(0),
+        Type::Vec4,
+        mesh.colors_0.iter().map(|c| &c[..]),
+    );
+    if !mesh.joints_0.is_empty() {
+        let bytes: Vec<u8> = mesh
+            .joints_0
+            .iter()
+            .flat_map(|joints| joints.iter().flat_map(|j| j.to_le_bytes()))
+            .collect();
+        let index = exported.push_accessor(
+            &bytes,
+            mesh.joints_0.len(),
+            ComponentType::U16,
+            Type::Vec4,
+            json::buffer::Target::ArrayBuffer,
+        );
+        exported
+            .primitive
+            .attributes
+            .insert(Valid(Semantic::Joints(0)), index);
+    }
+    exported.push_f32_attribute(
+        Semantic::Weights(0),
+        Type::Vec4,
+        mesh.weights_0.iter().map(|w| &w[..]),
+    );
+
+    if !mesh.indices.is_empty() {
+        // The maximum value of each type is reserved for primitive restart.
+        let (component_type, bytes): (_, Vec<u8>) =
+            if mesh.indices.iter().all(|&i| i < u32::from(u16::MAX)) {
+                let bytes = mesh
+                    .indices
+                    .iter()
+                    .flat_map(|&i| (i as u16).to_le_bytes())
+                    .collect();
+                (ComponentType::U16, bytes)
+            } else {
+                let bytes = mesh.indices.iter().flat_map(|i| i.to_le_bytes()).collect();
+                (ComponentType::U32, bytes)
+            };
+        let index = exported.push_accessor(
+            &bytes,
+            mesh.indices.len(),
+            component_type,
+            Type::Scalar,
+            json::buffer::Target::ElementArrayBuffer,
+        );
+        exported.primitive.indices = Some(index);
+    }
+
+    exported.buffer.byte_length = exported.bin.len() as u32;
+    exported
+}
+
+/// Writes the vertices and triangles of a triangle list as the `v`, `vt`,
+/// `vn`, and `f` lines of a Wavefront OBJ file.
+///
+/// Faces refer to vertices by 1-based indices. Texture co-ordinates of set 0
+/// and normals are written if every position has them. The `v` texture
+/// co-ordinate is flipped, since OBJ places the origin at the bottom of the
+/// image. Any trailing indices that do not form a whole triangle are ignored.
+pub fn obj(mesh: &SoaMesh) -> String {
+    use std::fmt::Write;
+
+    let count = mesh.positions.len();
+    let has_tex_coords = count > 0 && mesh.tex_coords_0.len() == count;
+    let has_normals = count > 0 && mesh.normals.len() == count;
+
+    let mut obj = String::new();
+    for [x, y, z] in &mesh.positions {
+        let _ = writeln!(obj, "v {} {} {}", x, y, z);
+    }
+    if has_tex_coords {
+        for [u, v] in &mesh.tex_coords_0 {
+            let _ = writeln!(obj, "vt {} {}", u, 1.0 - v);
+        }
+    }
+    if has_normals {
+        for [x, y, z] in &mesh.normals {
+            let _ = writeln!(obj, "vn {} {} {}", x, y, z);
+        }
+    }
+    for triangle in mesh.indices.chunks_exact(3) {
+        obj.push('f');
+        for &index in triangle {
+            let index = index + 1;
+            let _ = match (has_tex_coords, has_normals) {
+                (true, true) => write!(obj, " {}/{}/{}", index, index, index),
+                (true, false) => write!(obj, " {}/{}", index, index),
+                (false, true) => write!(obj, " {}//{}", index, index),
+                (false, false) => write!(obj, " {}", index),
+            };
+        }
+        obj.push('\n');
+    }
+    obj
+}
@@ -0,0 +1,96 @@
+use std::marker::PhantomData;
+
+use super::ReadFeatureIds;
+
+/// Casting iterator for `FeatureIds`.
+#[derive(Clone, Debug)]
+pub struct CastingIter<'a, T>(ReadFeatureIds<'a>, PhantomData<T>);
+
+/// Type which describes how to cast any feature ID into u32.
+#[derive(Clone, Debug)]
+pub struct U32;
+
+/// Trait for types which describe casting behaviour.
+pub trait Cast {
+    /// Output type.
+    type Output;
+
+    /// Cast from u8.
+    fn cast_u8(x: u8) -> Self::Output;
+
+    /// Cast from u16.
+    fn cast_u16(x: u16) -> Self::Output;
+
+    /// Cast from u32.
+    fn cast_u32(x: u32) -> Self::Output;
+
+    /// Cast from f32.
+    fn cast_f32(x: f32) -> Self::Output;
+}
+
+impl<'a, A> CastingIter<'a, A> {
+    pub(crate) fn new(iter: ReadFeatureIds<'a>) -> Self {
+        CastingIter(iter, PhantomData)
+    }
+
+    /// Unwrap underlying `FeatureIds` object.
+    pub fn unwrap(self) -> ReadFeatureIds<'a> {
+        self.0
+    }
+}
+
+impl<'a, A: Cast> ExactSizeIterator for CastingIter<'a, A> {}
+impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
+    type Item = A::Output;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.0 {
+            ReadFeatureIds::U8(ref mut i) => i.next().map(A::cast_u8),
+            ReadFeatureIds::U16(ref mut i) => i.next().map(A::cast_u16),
+            ReadFeatureIds::U32(ref mut i) => i.next().map(A::cast_u32),
+            ReadFeatureIds::F32(ref mut i) => i.next().map(A::cast_f32),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        match self.0 {
+            ReadFeatureIds::U8(ref mut i) => i.nth(x).map(A::cast_u8),
+            ReadFeatureIds::U16(ref mut i) => i.nth(x).map(A::cast_u16),
+            ReadFeatureIds::U32(ref mut i) => i.nth(x).map(A::cast_u32),
+            ReadFeatureIds::F32(ref mut i) => i.nth(x).map(A::cast_f32),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.0 {
+            ReadFeatureIds::U8(ref i) => i.size_hint(),
+            ReadFeatureIds::U16(ref i) => i.size_hint(),
+            ReadFeatureIds::U32(ref i) => i.size_hint(),
+            ReadFeatureIds::F32(ref i) => i.size_hint(),
+        }
+    }
+}
+
+impl Cast for U32 {
+    type Output = u32;
+
+    fn cast_u8(x: u8) -> Self::Output {
+        x as Self::Output
+    }
+    fn cast_u16(x: u16) -> Self::Output {
+        x as Self::Output
+    }
+    fn cast_u32(x: u32) -> Self::Output {
+        x
+    }
+    fn cast_f32(x: f32) -> Self::Output {
+        x as Self::Output
+    }
+}
@@ -0,0 +1,293 @@
+use super::soa::SoaMesh;
+
+pub(super) fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub(super) fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub(super) fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Rescales `v` to unit length, or returns `None` if it has zero length.
+fn normalize(v: [f32; 3]) -> Option<[f32; 3]> {
+    let length = dot(v, v).sqrt();
+    if length > 0.0 {
+        Some([v[0] / length, v[1] / length, v[2] / length])
+    } else {
+        None
+    }
+}
+
+/// Returns an arbitrary unit vector perpendicular to the unit vector `n`.
+fn perpendicular(n: [f32; 3]) -> [f32; 3] {
+    let axis = if n[0].abs() < 0.9 {
+        [1.0, 0.0, 0.0]
+    } else {
+        [0.0, 1.0, 0.0]
+    };
+    normalize(cross(n, axis)).unwrap_or([1.0, 0.0, 0.0])
+}
+
+/// Computes the geometric normal of each triangle from its winding order.
+///
+/// Triangles of zero area are given the normal `[0.0, 0.0, 1.0]`.
+pub fn face_normals(triangles: &[[[f32; 3]; 3]]) -> Vec<[f32; 3]> {
+    triangles
+        .iter()
+        .map(|&[a, b, c]| normalize(cross(sub(b, a), sub(c, a))).unwrap_or([0.0, 0.0, 1.0]))
+        .collect()
+}
+
+/// Generates smooth vertex normals by averaging the normals of the triangles
+/// sharing each vertex, weighted by triangle area.
+///
+/// Vertices that belong to no triangle of non-zero area are given the normal
+/// `[0.0, 0.0, 1.0]`.
+///
+/// # Panics
+///
+/// Panics if a triangle refers to a vertex out of bounds of `positions`.
+pub fn smooth_normals(positions: &[[f32; 3]], triangles: &[[u32; 3]]) -> Vec<[f32; 3]> {
+    let mut normals = vec![[0.0; 3]; positions.len()];
+    for triangle in triangles {
+        let [a, b, c] = [
+            positions[triangle[0] as usize],
+            positions[triangle[1] as usize],
+            positions[triangle[2] as usize],
+        ];
+        // The magnitude of the cross product is twice the triangle area.
+        let face = cross(sub(b, a), sub(c, a));
+        for &index in triangle {
+            let normal = &mut normals[index as usize];
+            for (component, offset) in normal.iter_mut().zip(face.iter()) {
+                *component += offset;
+            }
+        }
+    }
+    normals
+        .into_iter()
+        .map(|normal| normalize(normal).unwrap_or([0.0, 0.0, 1.0]))
+        .collect()
+}
+
+/// Generates vertex tangents from the texture co-ordinates of each triangle.
+///
+/// The tangents follow the direction of increasing `u` and are made
+/// orthogonal to the given unit normals. The `w` component holds the
+/// handedness of the tangent basis as described by the glTF specification.
+/// Vertices without usable texture co-ordinates are given an arbitrary tangent
+/// perpendicular to their normal.
+///
+/// # Panics
+///
+/// Panics if a triangle refers to a vertex out of bounds of `positions`,
+/// `normals`, or `tex_coords`.
+pub fn tangents(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    tex_coords: &[[f32; 2]],
+    triangles: &[[u32; 3]],
+) -> Vec<[f32; 4]> {
+    let mut tangents = vec![[0.0; 3]; positions.len()];
+    let mut bitangents = vec![[0.0; 3]; positions.len()];
+    for triangle in triangles {
+        let [i, j, k] = [
+            triangle[0] as usize,
+            triangle[1] as usize,
+            triangle[2] as usize,
+        ];
+        let e1 = sub(positions[j], positions[i]);
+        let e2 = sub(positions[k], positions[i]);
+        let (du1, dv1) = (
+            tex_coords[j][0] - tex_coords[i][0],
+            tex_coords[j][1] - tex_coords[i][1],
+        );
+        let (du2, dv2) = (
+            tex_coords[k][0] - tex_coords[i][0],
+            tex_coords[k][1] - tex_coords[i][1],
+        );
+        let determinant = du1 * dv2 - du2 * dv1;
+        if determinant == 0.0 {
+            continue;
+        }
+        let r = 1.0 / determinant;
+        let tangent = [
+            (e1[0] * dv2 - e2[0] * dv1) * r,
+            (e1[1] * dv2 - e2[1] * dv1) * r,
+            (e1[2] * dv2 - e2[2] * dv1) * r,
+        ];
+        let bitangent = [
+            (e2[0] * du1 - e1[0] * du2) * r,
+            (e2[1] * du1 - e1[1] * du2) * r,
+            (e2[2] * du1 - e1[2] * du2) * r,
+        ];
+        for &index in &[i, j, k] {
+            for c in 0..3 {
+                tangents[index][c] += tangent[c];
+                bitangents[index][c] += bitangent[c];
+            }
+        }
+    }
+
+    tangents
+        .into_iter()
+        .zip(bitangents)
+        .zip(normals)
+        .map(|((tangent, bitangent), &normal)| {
+            // Gram-Schmidt orthogonalization against the normal.
+            let projection = dot(normal, tangent);
+            let orthogonal = [
+                tangent[0] - normal[0] * projection,
+                tangent[1] - normal[1] * projection,
+                tangent[2] - normal[2] * projection,
+            ];
+            let t = normalize(orthogonal).unwrap_or_else(|| perpendicular(normal));
+            // glTF texture co-ordinates have `v` pointing down the image,
+            // while the bitangent points up, towards decreasing `v`.
+            let w = if dot(cross(normal, t), bitangent) > 0.0 {
+                -1.0
+            } else {
+                1.0
+            };
+            [t[0], t[1], t[2], w]
+        })
+        .collect()
+}
+
+/// A face-vertex view of an indexed triangle list, shaped after the
+/// `Geometry` trait of the `mikktspace` crate.
+///
+/// With the `mikktspace` feature, this type implements `mikktspace::Geometry`
+/// so that MikkTSpace tangents can be generated into the mesh with
+/// [`TangentGeometry::generate_mikktspace_tangents`].
+/// [`TangentGeometry::generate_tangents`] uses the simpler generator of this
+/// module instead.
+#[derive(Debug)]
+pub struct TangentGeometry<'a> {
+    mesh: &'a mut SoaMesh,
+}
+
+impl<'a> TangentGeometry<'a> {
+    /// Wraps an indexed triangle list with normals and texture co-ordinates
+    /// of set 0 for every vertex.
+    ///
+    /// Missing tangents are initialized to `[1.0, 0.0, 0.0, 1.0]`. Returns
+    /// `None` if the mesh is not a valid indexed triangle list or lacks
+    /// normals or texture co-ordinates.
+    pub fn new(mesh: &'a mut SoaMesh) -> Option<Self> {
+        let count = mesh.positions.len();
+        let valid = mesh.indices.chunks_exact(3).remainder().is_empty()
+            && mesh.indices.iter().all(|&index| (index as usize) < count)
+            && mesh.normals.len() == count
+            && mesh.tex_coords_0.len() == count;
+        if !valid {
+            return None;
+        }
+        mesh.tangents.resize(count, [1.0, 0.0, 0.0, 1.0]);
+        Some(Self { mesh })
+    }
+
+    fn vertex(&self, face: usize, vert: usize) -> usize {
+        self.mesh.indices[face * 3 + vert] as usize
+    }
+
+    /// Returns the number of triangles.
+    pub fn num_faces(&self) -> usize {
+        self.mesh.indices.len() / 3
+    }
+
+    /// Returns the number of vertices of a face, which is always 3.
+    pub fn num_vertices_of_face(&self, _face: usize) -> usize {
+        3
+    }
+
+    /// Returns the position of vertex `vert` of triangle `face`.
+    pub fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.mesh.positions[self.vertex(face, vert)]
+    }
+
+    /// Returns the normal of vertex `vert` of triangle `face`.
+    pub fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.mesh.normals[self.vertex(face, vert)]
+    }
+
+    /// Returns the texture co-ordinates of vertex `vert` of triangle `face`.
+    pub fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+        self.mesh.tex_coords_0[self.vertex(face, vert)]
+    }
+
+    /// Stores the tangent of vertex `vert` of triangle `face`, where `w` holds
+    /// the handedness.
+    ///
+    /// Vertices shared between triangles keep the last tangent stored.
+    pub fn set_tangent_encoded(&mut self, tangent: [f32; 4], face: usize, vert: usize) {
+        let vertex = self.vertex(face, vert);
+        self.mesh.tangents[vertex] = tangent;
+    }
+
+    /// Generates the tangents of the mesh with [`tangents`].
+    pub fn generate_tangents(&mut self) {
+        let triangles: Vec<[u32; 3]> = self
+            .mesh
+            .indices
+            .chunks_exact(3)
+            .map(|t| [t[0], t[1], t[2]])
+            .collect();
+        let generated = tangents(
+            &self.mesh.positions,
+            &self.mesh.normals,
+            &self.mesh.tex_coords_0,
+            &triangles,
+        );
+        for face in 0..self.num_faces() {
+            for vert in 0..self.num_vertices_of_face(face) {
+                let tangent = generated[self.vertex(face, vert)];
+                self.set_tangent_encoded(tangent, face, vert);
+            }
+        }
+    }
+
+    /// Generates MikkTSpace tangents into the mesh.
+    ///
+    /// Returns `false` if `mikktspace` failed to generate the tangents, in
+    /// which case they may be partially written.
+    #[cfg(feature = "mikktspace")]
+    pub fn generate_mikktspace_tangents(&mut self) -> bool {
+        mikktspace::generate_tangents(self)
+    }
+}
+
+#[cfg(feature = "mikktspace")]
+impl mikktspace::Geometry for TangentGeometry<'_> {
+    fn num_faces(&self) -> usize {
+        TangentGeometry::num_faces(self)
+    }
+
+    fn num_vertices_of_face(&self, face: usize) -> usize {
+        TangentGeometry::num_vertices_of_face(self, face)
+    }
+
+    fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+        TangentGeometry::position(self, face, vert)
+    }
+
+    fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+        TangentGeometry::normal(self, face, vert)
+    }
+
+    fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+        TangentGeometry::tex_coord(self, face, vert)
+    }
+
+    fn set_tangent_encoded(&mut self, tangent: [f32; 4], face: usize, vert: usize) {
+        TangentGeometry::set_tangent_encoded(self, tangent, face, vert)
+    }
+}
@@ -0,0 +1,148 @@
+use std::{error, fmt, mem};
+
+use super::soa::SoaMesh;
+use crate::Material;
+
+/// Specifies how to reconcile materials that read different texture
+/// co-ordinate sets when merging primitives.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TexCoordPolicy {
+    /// Fail with [`MergeError::TexCoordConflict`].
+    Error,
+
+    /// Swap the texture co-ordinate sets of the second primitive so that it
+    /// reads the same set as the first.
+    ///
+    /// This is only possible when each material reads a single set.
+    Remap,
+}
+
+/// An error that occurred while merging primitives.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeError {
+    /// The primitives provide different sets of vertex attributes.
+    AttributeMismatch,
+
+    /// The materials of the primitives read different texture co-ordinate
+    /// sets.
+    TexCoordConflict {
+        /// The sets read by the material of the first primitive.
+        first: Vec<u32>,
+        /// The sets read by the material of the second primitive.
+        second: Vec<u32>,
+    },
+
+    /// A material reads a texture co-ordinate set that cannot be remapped.
+    UnsupportedTexCoordSet(u32),
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MergeError::AttributeMismatch => write!(f, "primitives have different attributes"),
+            MergeError::TexCoordConflict {
+                ref first,
+                ref second,
+            } => write!(
+                f,
+                "materials read different texture co-ordinate sets: {:?} and {:?}",
+                first, second
+            ),
+            MergeError::UnsupportedTexCoordSet(set) => {
+                write!(f, "texture co-ordinate set {} cannot be remapped", set)
+            }
+        }
+    }
+}
+
+impl error::Error for MergeError {}
+
+fn append<T: Copy>(merged: &mut Vec<T>, a: &[T], b: &[T]) -> Result<(), MergeError> {
+    if a.is_empty() != b.is_empty() {
+        return Err(MergeError::AttributeMismatch);
+    }
+    merged.extend_from_slice(a);
+    merged.extend_from_slice(b);
+    Ok(())
+}
+
+/// Merges the data of two primitives into one, rendered with the material of
+/// the first.
+///
+/// Both primitives must provide the same vertex attributes and be drawn with
+/// the same mode. The vertices of `second` follow those of `first`, and its
+/// indices are offset accordingly; if only one primitive is indexed, indices
+/// are generated for the other.
+///
+/// When the materials read different texture co-ordinate sets, the merged
+/// primitive would render the second primitive with the wrong set. `policy`
+/// decides whether this is an error or the sets of `second` are remapped.
+/// Materials that read no texture co-ordinates never conflict.
+pub fn merge(
+    first: &SoaMesh,
+    first_material: &Material,
+    second: &SoaMesh,
+    second_material: &Material,
+    policy: TexCoordPolicy,
+) -> Result<SoaMesh, MergeError> {
+    let first_sets = first_material.tex_coord_sets();
+    let second_sets = second_material.tex_coord_sets();
+    let mut remapped;
+    let second = if first_sets.is_empty() || second_sets.is_empty() || first_sets == second_sets {
+        second
+    } else {
+        match (policy, &first_sets[..], &second_sets[..]) {
+            (TexCoordPolicy::Remap, &[to], &[from]) => {
+                // Only sets 0 and 1 are stored, so the sets differ if and only
+                // if they are exactly those two.
+                if let Some(&set) = [to, from].iter().find(|&&set| set > 1) {
+                    return Err(MergeError::UnsupportedTexCoordSet(set));
+                }
+                remapped = second.clone();
+                mem::swap(&mut remapped.tex_coords_0, &mut remapped.tex_coords_1);
+                &remapped
+            }
+            _ => {
+                return Err(MergeError::TexCoordConflict {
+                    first: first_sets,
+                    second: second_sets,
+                })
+            }
+        }
+    };
+
+    let mut merged = SoaMesh::default();
+    append(&mut merged.positions, &first.positions, &second.positions)?;
+    append(&mut merged.normals, &first.normals, &second.normals)?;
+    append(&mut merged.tangents, &first.tangents, &second.tangents)?;
+    append(
+        &mut merged.tex_coords_0,
+        &first.tex_coords_0,
+        &second.tex_coords_0,
+    )?;
+    append(
+        &mut merged.tex_coords_1,
+        &first.tex_coords_1,
+        &second.tex_coords_1,
+    )?;
+    append(&mut merged.colors_0, &first.colors_0, &second.colors_0)?;
+    append(&mut merged.joints_0, &first.joints_0, &second.joints_0)?;
+    append(&mut merged.weights_0, &first.weights_0, &second.weights_0)?;
+
+    if !first.indices.is_empty() || !second.indices.is_empty() {
+        let sequence = |mesh: &SoaMesh| -> Vec<u32> {
+            if mesh.indices.is_empty() {
+                (0..mesh.positions.len() as u32).collect()
+            } else {
+                mesh.indices.clone()
+            }
+        };
+        let offset = first.positions.len() as u32;
+        merged.indices = sequence(first);
+        merged
+            .indices
+            .extend(sequence(second).into_iter().map(|index| index + offset));
+    }
+
+    Ok(merged)
+}
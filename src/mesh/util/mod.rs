@@ -1,15 +1,46 @@
+/// Bounding volume hierarchies for ray queries.
+pub mod bvh;
+
 /// Casting iterator adapters for colors.
 pub mod colors;
 
+/// Casting iterator adapters for feature IDs.
+#[cfg(feature = "EXT_mesh_features")]
+pub mod feature_ids;
+
+/// Export of primitive data to packed glTF buffers.
+pub mod export;
+
+/// Generation of missing vertex attributes.
+pub mod generate;
+
 /// Casting iterator adapters for vertex indices.
 pub mod indices;
 
 /// Casting iterator adapters for joint indices.
 pub mod joints;
 
+/// Merging of primitive data.
+pub mod merge;
+
+/// Vertex cache and vertex fetch optimizations of index buffers.
+pub mod optimize;
+
+/// Quadric error mesh simplification of triangle lists.
+pub mod simplify;
+
+/// Struct-of-arrays containers for primitive data.
+pub mod soa;
+
 /// Casting iterator adapters for texture co-ordinates.
 pub mod tex_coords;
 
+/// Helpers for expanding primitive topologies.
+pub mod topology;
+
+/// Per-vertex attribute containers.
+pub mod vertex;
+
 /// Casting iterator adapters for node weights.
 pub mod weights;
 
@@ -21,12 +52,31 @@ use crate::Buffer;
 /// XYZ vertex positions of type `[f32; 3]`.
 pub type ReadPositions<'a> = Iter<'a, [f32; 3]>;
 
+/// XYZ vertex positions of type `[f64; 3]`.
+#[cfg(feature = "EXT_accessor_double")]
+#[derive(Clone, Debug)]
+pub enum ReadPositionsF64<'a> {
+    /// Positions stored with `f64` components by the `EXT_accessor_double`
+    /// extension.
+    F64(crate::accessor::util::ItemIter<'a, [f64; 3]>),
+    /// Positions stored with `f32` components.
+    F32(ReadPositions<'a>),
+}
+
 /// XYZ vertex normals of type `[f32; 3]`.
 pub type ReadNormals<'a> = Iter<'a, [f32; 3]>;
 
 /// XYZW vertex tangents of type `[f32; 4]` where the `w` component is a
 /// sign value (-1 or +1) indicating the handedness of the tangent basis.
-pub type ReadTangents<'a> = Iter<'a, [f32; 4]>;
+#[derive(Clone, Debug)]
+pub enum ReadTangents<'a> {
+    /// Tangents stored as `VEC4`, as required by the specification.
+    Vec4(Iter<'a, [f32; 4]>),
+    /// Tangents of non-conformant assets stored as `VEC3`, which are given a
+    /// handedness of +1.
+    #[cfg(feature = "lenient")]
+    Vec3(Iter<'a, [f32; 3]>),
+}
 
 /// XYZ vertex position displacements of type `[f32; 3]`.
 pub type ReadPositionDisplacements<'a> = Iter<'a, [f32; 3]>;
@@ -100,6 +150,41 @@ pub enum ReadWeights<'a> {
     F32(Iter<'a, [f32; 4]>),
 }
 
+/// Feature IDs as defined by the `EXT_mesh_features` extension.
+#[cfg(feature = "EXT_mesh_features")]
+#[derive(Clone, Debug)]
+pub enum ReadFeatureIds<'a> {
+    /// Feature IDs of type `u8`.
+    U8(Iter<'a, u8>),
+    /// Feature IDs of type `u16`.
+    U16(Iter<'a, u16>),
+    /// Feature IDs of type `u32`.
+    U32(Iter<'a, u32>),
+    /// Feature IDs of type `f32`.
+    F32(Iter<'a, f32>),
+}
+
+/// Data of an application-specific vertex attribute such as `_BATCHID`.
+///
+/// The components of every element are stored consecutively, with any matrix
+/// column padding removed.
+#[cfg(feature = "extras")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeData {
+    /// Components of type `i8`.
+    I8(Vec<i8>),
+    /// Components of type `u8`.
+    U8(Vec<u8>),
+    /// Components of type `i16`.
+    I16(Vec<i16>),
+    /// Components of type `u16`.
+    U16(Vec<u16>),
+    /// Components of type `u32`.
+    U32(Vec<u32>),
+    /// Components of type `f32`.
+    F32(Vec<f32>),
+}
+
 /// Morph targets.
 #[derive(Clone, Debug)]
 pub struct ReadMorphTargets<'a, 's, F>
@@ -185,6 +270,68 @@ impl<'a> ReadColors<'a> {
     pub fn into_rgba_f32(self) -> self::colors::CastingIter<'a, self::colors::RgbaF32> {
         self::colors::CastingIter::new(self)
     }
+
+    /// Reinterpret colors as RGBA f32, giving RGB colors the alpha `alpha`
+    /// instead of `1.0`.  Lossy if the underlying iterator yields u16.
+    pub fn into_rgba_f32_with_alpha(self, alpha: f32) -> self::colors::WithAlpha<'a> {
+        self::colors::WithAlpha::new(self, alpha)
+    }
+}
+
+#[cfg(feature = "EXT_accessor_double")]
+impl<'a> ReadPositionsF64<'a> {
+    /// Reinterpret positions as `[f32; 3]`.  Lossy if the underlying iterator
+    /// yields `[f64; 3]`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_f32(self) -> std::iter::Map<Self, fn([f64; 3]) -> [f32; 3]> {
+        self.map(|[x, y, z]| [x as f32, y as f32, z as f32])
+    }
+}
+
+#[cfg(feature = "EXT_accessor_double")]
+impl<'a> ExactSizeIterator for ReadPositionsF64<'a> {}
+
+#[cfg(feature = "EXT_accessor_double")]
+impl<'a> Iterator for ReadPositionsF64<'a> {
+    type Item = [f64; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            ReadPositionsF64::F64(iter) => iter.next(),
+            ReadPositionsF64::F32(iter) => iter
+                .next()
+                .map(|[x, y, z]| [f64::from(x), f64::from(y), f64::from(z)]),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            ReadPositionsF64::F64(iter) => iter.size_hint(),
+            ReadPositionsF64::F32(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadTangents<'a> {}
+
+impl<'a> Iterator for ReadTangents<'a> {
+    type Item = [f32; 4];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            ReadTangents::Vec4(iter) => iter.next(),
+            #[cfg(feature = "lenient")]
+            ReadTangents::Vec3(iter) => iter.next().map(|[x, y, z]| [x, y, z, 1.0]),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            ReadTangents::Vec4(iter) => iter.size_hint(),
+            #[cfg(feature = "lenient")]
+            ReadTangents::Vec3(iter) => iter.size_hint(),
+        }
+    }
 }
 
 impl<'a> ReadIndices<'a> {
@@ -194,6 +341,14 @@ impl<'a> ReadIndices<'a> {
     }
 }
 
+#[cfg(feature = "EXT_mesh_features")]
+impl<'a> ReadFeatureIds<'a> {
+    /// Reinterpret feature IDs as u32, which can fit any possible feature ID.
+    pub fn into_u32(self) -> self::feature_ids::CastingIter<'a, self::feature_ids::U32> {
+        self::feature_ids::CastingIter::new(self)
+    }
+}
+
 impl<'a> ReadJoints<'a> {
     /// Reinterpret joints as u16, which can fit any possible joint.
     pub fn into_u16(self) -> self::joints::CastingIter<'a, self::joints::U16> {
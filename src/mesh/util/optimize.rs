@@ -0,0 +1,192 @@
+//! The vertex cache optimization follows Tom Forsyth's "Linear-Speed Vertex
+//! Cache Optimisation".
+
+/// The size of the modelled post-transform vertex cache.
+const CACHE_SIZE: usize = 32;
+
+/// The exponent of the score falloff with position in the cache.
+const CACHE_DECAY_POWER: f32 = 1.5;
+
+/// The score of the vertices of the most recently emitted triangle, which is
+/// lowered so that the rest of the cache is preferred.
+const LAST_TRIANGLE_SCORE: f32 = 0.75;
+
+/// The weight of the bonus for vertices with few remaining triangles.
+const VALENCE_BOOST_SCALE: f32 = 2.0;
+
+/// The exponent of the bonus for vertices with few remaining triangles.
+const VALENCE_BOOST_POWER: f32 = 0.5;
+
+fn vertex_score(cache_position: Option<usize>, remaining_triangles: usize) -> f32 {
+    if remaining_triangles == 0 {
+        return -1.0;
+    }
+    let cache_score = match cache_position {
+        Some(position) if position < 3 => LAST_TRIANGLE_SCORE,
+        Some(position) => {
+            let scale = 1.0 / (CACHE_SIZE - 3) as f32;
+            (1.0 - (position - 3) as f32 * scale).powf(CACHE_DECAY_POWER)
+        }
+        None => 0.0,
+    };
+    let valence_boost =
+        VALENCE_BOOST_SCALE * (remaining_triangles as f32).powf(-VALENCE_BOOST_POWER);
+    cache_score + valence_boost
+}
+
+/// Reorders the triangles of a triangle list to improve the hit rate of the
+/// post-transform vertex cache of the GPU.
+///
+/// Only the order of the triangles changes; the vertices of each triangle
+/// keep their order. Any trailing indices that do not form a whole triangle
+/// are left in place.
+pub fn optimize_vertex_cache(indices: &mut [u32]) {
+    let triangle_count = indices.len() / 3;
+    if triangle_count == 0 {
+        return;
+    }
+    // Renumber the vertices so that the allocations below are bounded by the
+    // number of indices rather than by their largest value.
+    let mut compact = indices[..triangle_count * 3].to_vec();
+    let originals = optimize_vertex_fetch(&mut compact);
+    let vertex_count = originals.len();
+
+    let mut adjacency = vec![Vec::new(); vertex_count];
+    for (triangle, vertices) in compact.chunks_exact(3).enumerate() {
+        for &vertex in vertices {
+            adjacency[vertex as usize].push(triangle);
+        }
+    }
+    let mut cache_positions = vec![None; vertex_count];
+    let mut vertex_scores: Vec<f32> = adjacency
+        .iter()
+        .map(|triangles| vertex_score(None, triangles.len()))
+        .collect();
+    let triangles: Vec<[u32; 3]> = compact
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]])
+        .collect();
+    let mut triangle_scores: Vec<f32> = triangles
+        .iter()
+        .map(|t| t.iter().map(|&v| vertex_scores[v as usize]).sum())
+        .collect();
+    let mut emitted = vec![false; triangle_count];
+    let mut cache: Vec<u32> = Vec::with_capacity(CACHE_SIZE + 3);
+    let mut output = Vec::with_capacity(triangle_count * 3);
+    // Triangles before this position have all been emitted.
+    let mut cursor = 0;
+
+    let mut best = (0..triangle_count).max_by(|&a, &b| {
+        triangle_scores[a]
+            .partial_cmp(&triangle_scores[b])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    while let Some(triangle) = best {
+        emitted[triangle] = true;
+        output.extend_from_slice(&triangles[triangle]);
+
+        // Move the vertices of the triangle to the front of the cache.
+        let mut new_cache = triangles[triangle].to_vec();
+        for &vertex in &triangles[triangle] {
+            let adjacent = &mut adjacency[vertex as usize];
+            if let Some(position) = adjacent.iter().position(|&t| t == triangle) {
+                adjacent.swap_remove(position);
+            }
+        }
+        new_cache.extend(
+            cache
+                .iter()
+                .copied()
+                .filter(|vertex| !triangles[triangle].contains(vertex)),
+        );
+        let evicted = new_cache.split_off(CACHE_SIZE.min(new_cache.len()));
+        for &vertex in &evicted {
+            let vertex = vertex as usize;
+            cache_positions[vertex] = None;
+            vertex_scores[vertex] = vertex_score(None, adjacency[vertex].len());
+        }
+        cache = new_cache;
+
+        // Rescore the cached and evicted vertices and their triangles.
+        for (position, &vertex) in cache.iter().enumerate() {
+            cache_positions[vertex as usize] = Some(position);
+        }
+        best = None;
+        let mut best_score = -1.0;
+        for &vertex in &cache {
+            let vertex = vertex as usize;
+            vertex_scores[vertex] = vertex_score(cache_positions[vertex], adjacency[vertex].len());
+        }
+        for &vertex in &cache {
+            for &adjacent in &adjacency[vertex as usize] {
+                let score = triangles[adjacent]
+                    .iter()
+                    .map(|&v| vertex_scores[v as usize])
+                    .sum();
+                triangle_scores[adjacent] = score;
+                if score > best_score {
+                    best_score = score;
+                    best = Some(adjacent);
+                }
+            }
+        }
+
+        // Fall back to the next triangle in the input order.
+        if best.is_none() {
+            while cursor < triangle_count && emitted[cursor] {
+                cursor += 1;
+            }
+            if cursor < triangle_count {
+                best = Some(cursor);
+            }
+        }
+    }
+
+    for (index, &vertex) in indices.iter_mut().zip(&output) {
+        *index = originals[vertex as usize];
+    }
+}
+
+/// Renumbers vertices in the order in which `indices` first refers to them,
+/// which improves the memory locality of vertex fetches.
+///
+/// The indices are rewritten in place. Returns, for each new vertex, the
+/// index of the original vertex. Vertices that are never referred to are
+/// omitted.
+pub fn optimize_vertex_fetch(indices: &mut [u32]) -> Vec<u32> {
+    let mut remap = std::collections::HashMap::new();
+    let mut order = Vec::new();
+    for index in indices.iter_mut() {
+        let original = *index;
+        *index = *remap.entry(original).or_insert_with(|| {
+            order.push(original);
+            order.len() as u32 - 1
+        });
+    }
+    order
+}
+
+/// Returns the average cache miss ratio (ACMR) of a triangle list: the number
+/// of vertices transformed per triangle, as modelled by a FIFO vertex cache
+/// of `cache_size` entries.
+///
+/// The ratio ranges from about 0.5 for well-ordered regular meshes to 3.0 when
+/// no vertex is reused. Returns 0.0 if there are no triangles.
+pub fn average_cache_miss_ratio(indices: &[u32], cache_size: usize) -> f32 {
+    let triangle_count = indices.len() / 3;
+    if triangle_count == 0 {
+        return 0.0;
+    }
+    let mut cache = std::collections::VecDeque::with_capacity(cache_size + 1);
+    let mut misses = 0;
+    for &index in &indices[..triangle_count * 3] {
+        if !cache.contains(&index) {
+            misses += 1;
+            cache.push_back(index);
+            if cache.len() > cache_size {
+                cache.pop_front();
+            }
+        }
+    }
+    misses as f32 / triangle_count as f32
+}
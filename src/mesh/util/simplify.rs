@@ -0,0 +1,330 @@
+//! The simplification follows Garland and Heckbert's "Surface Simplification
+//! Using Quadric Error Metrics", restricted to collapsing each edge onto one
+//! of its end points so that the attributes of the surviving vertices stay
+//! valid.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// The weight of the planes that keep open boundaries in place, relative to
+/// the planes of the triangles.
+const BOUNDARY_WEIGHT: f64 = 10.0;
+
+/// A symmetric 4x4 matrix measuring the squared distance of a point to a set
+/// of planes, stored as its upper triangle.
+#[derive(Clone, Copy, Debug, Default)]
+struct Quadric([f64; 10]);
+
+impl Quadric {
+    /// Returns the quadric of the plane through `point` with unit `normal`,
+    /// scaled by `weight`.
+    fn plane(normal: [f64; 3], point: [f64; 3], weight: f64) -> Self {
+        let [a, b, c] = normal;
+        let d = -(a * point[0] + b * point[1] + c * point[2]);
+        let mut values = [
+            a * a,
+            a * b,
+            a * c,
+            a * d,
+            b * b,
+            b * c,
+            b * d,
+            c * c,
+            c * d,
+            d * d,
+        ];
+        for value in values.iter_mut() {
+            *value *= weight;
+        }
+        Quadric(values)
+    }
+
+    fn add(&mut self, other: &Quadric) {
+        for (q, o) in self.0.iter_mut().zip(other.0.iter()) {
+            *q += o;
+        }
+    }
+
+    fn error(&self, [x, y, z]: [f64; 3]) -> f64 {
+        let q = &self.0;
+        q[0] * x * x
+            + 2.0 * q[1] * x * y
+            + 2.0 * q[2] * x * z
+            + 2.0 * q[3] * x
+            + q[4] * y * y
+            + 2.0 * q[5] * y * z
+            + 2.0 * q[6] * y
+            + q[7] * z * z
+            + 2.0 * q[8] * z
+            + q[9]
+    }
+}
+
+/// A candidate collapse of vertex `from` onto vertex `to`.
+#[derive(Clone, Copy, Debug)]
+struct Collapse {
+    cost: f64,
+    from: u32,
+    to: u32,
+    /// The versions of `from` and `to` when the candidate was computed.
+    versions: [u32; 2],
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Collapse {
+    /// Orders cheaper collapses first in a max-heap.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .partial_cmp(&self.cost)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Returns `corners` with vertex `from` replaced by `to`.
+fn replace(mut corners: [u32; 3], from: u32, to: u32) -> [u32; 3] {
+    for corner in corners.iter_mut() {
+        if *corner == from {
+            *corner = to;
+        }
+    }
+    corners
+}
+
+fn normalize(v: [f64; 3]) -> Option<[f64; 3]> {
+    let length = dot(v, v).sqrt();
+    if length > 0.0 {
+        Some([v[0] / length, v[1] / length, v[2] / length])
+    } else {
+        None
+    }
+}
+
+struct Simplifier {
+    positions: Vec<[f64; 3]>,
+    quadrics: Vec<Quadric>,
+    versions: Vec<u32>,
+    triangles: Vec<Option<[u32; 3]>>,
+    /// The triangles around each vertex, which may include dead triangles.
+    adjacency: Vec<Vec<usize>>,
+    heap: BinaryHeap<Collapse>,
+}
+
+impl Simplifier {
+    fn normal(&self, [a, b, c]: [u32; 3]) -> [f64; 3] {
+        let [a, b, c] = [
+            self.positions[a as usize],
+            self.positions[b as usize],
+            self.positions[c as usize],
+        ];
+        cross(sub(b, a), sub(c, a))
+    }
+
+    fn push(&mut self, from: u32, to: u32) {
+        // Collapsing a vertex onto itself would remove every triangle around
+        // it.
+        if from == to {
+            return;
+        }
+        let mut quadric = self.quadrics[from as usize];
+        quadric.add(&self.quadrics[to as usize]);
+        self.heap.push(Collapse {
+            cost: quadric.error(self.positions[to as usize]),
+            from,
+            to,
+            versions: [self.versions[from as usize], self.versions[to as usize]],
+        });
+    }
+
+    fn push_edges(&mut self, vertex: u32) {
+        let mut neighbours = Vec::new();
+        for &triangle in &self.adjacency[vertex as usize] {
+            if let Some(corners) = self.triangles[triangle] {
+                neighbours.extend(corners.iter().copied().filter(|&v| v != vertex));
+            }
+        }
+        neighbours.sort_unstable();
+        neighbours.dedup();
+        for neighbour in neighbours {
+            self.push(vertex, neighbour);
+            self.push(neighbour, vertex);
+        }
+    }
+
+    /// Returns `true` if moving `from` onto `to` would flip or degenerate a
+    /// triangle that survives the collapse.
+    fn flips(&self, from: u32, to: u32) -> bool {
+        self.adjacency[from as usize].iter().any(|&triangle| {
+            let corners = match self.triangles[triangle] {
+                Some(corners) if !corners.contains(&to) => corners,
+                _ => return false,
+            };
+            let before = self.normal(corners);
+            let moved = replace(corners, from, to);
+            let after = self.normal(moved);
+            dot(after, after) == 0.0 || dot(before, after) < 0.0
+        })
+    }
+
+    /// Collapses `from` onto `to`, returning the number of removed triangles.
+    fn collapse(&mut self, from: u32, to: u32) -> usize {
+        let mut removed = 0;
+        let around = std::mem::take(&mut self.adjacency[from as usize]);
+        for triangle in around {
+            let corners = match self.triangles[triangle] {
+                Some(corners) => corners,
+                None => continue,
+            };
+            if corners.contains(&to) {
+                self.triangles[triangle] = None;
+                removed += 1;
+            } else {
+                self.triangles[triangle] = Some(replace(corners, from, to));
+                self.adjacency[to as usize].push(triangle);
+            }
+        }
+        let quadric = self.quadrics[from as usize];
+        self.quadrics[to as usize].add(&quadric);
+        self.versions[from as usize] += 1;
+        self.versions[to as usize] += 1;
+        self.push_edges(to);
+        removed
+    }
+}
+
+/// Reduces the number of triangles of a triangle list to about `target_ratio`
+/// of the original by repeatedly collapsing the edge whose removal changes
+/// the shape the least.
+///
+/// Each edge is collapsed onto one of its end points, so the returned
+/// triangle list refers to a subset of the original vertices and needs no
+/// new vertex data. Triangles keep their winding order, collapses that would
+/// flip a triangle are skipped, and open boundaries are kept in place as far
+/// as possible. Degenerate triangles, which repeat a corner, and any trailing
+/// indices that do not form a whole triangle are dropped; `target_ratio`
+/// applies to the remaining triangles.
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds of `positions`.
+pub fn simplify(positions: &[[f32; 3]], indices: &[u32], target_ratio: f32) -> Vec<u32> {
+    let triangles: Vec<Option<[u32; 3]>> = indices
+        .chunks_exact(3)
+        .filter(|t| t[0] != t[1] && t[1] != t[2] && t[2] != t[0])
+        .map(|t| Some([t[0], t[1], t[2]]))
+        .collect();
+    let target = (triangles.len() as f32 * target_ratio.max(0.0)).ceil() as usize;
+
+    let mut simplifier = Simplifier {
+        positions: positions
+            .iter()
+            .map(|p| [p[0] as f64, p[1] as f64, p[2] as f64])
+            .collect(),
+        quadrics: vec![Quadric::default(); positions.len()],
+        versions: vec![0; positions.len()],
+        adjacency: vec![Vec::new(); positions.len()],
+        triangles,
+        heap: BinaryHeap::new(),
+    };
+
+    let mut edges: HashMap<[u32; 2], (usize, [u32; 2])> = HashMap::new();
+    for (triangle, corners) in simplifier.triangles.clone().into_iter().enumerate() {
+        let corners = corners.unwrap();
+        let normal = simplifier.normal(corners);
+        // The magnitude of the cross product is twice the triangle area.
+        let area = dot(normal, normal).sqrt() / 2.0;
+        if let Some(unit) = normalize(normal) {
+            let point = simplifier.positions[corners[0] as usize];
+            let quadric = Quadric::plane(unit, point, area);
+            for &corner in &corners {
+                simplifier.quadrics[corner as usize].add(&quadric);
+            }
+        }
+        for i in 0..3 {
+            let (a, b) = (corners[i], corners[(i + 1) % 3]);
+            simplifier.adjacency[a as usize].push(triangle);
+            let key = [a.min(b), a.max(b)];
+            edges.entry(key).or_insert((0, [a, b])).0 += 1;
+        }
+    }
+
+    // Edges used by a single triangle lie on an open boundary; constrain
+    // them with a plane through the edge, perpendicular to the triangle.
+    for &(_, [a, b]) in edges.values().filter(|(uses, _)| *uses == 1) {
+        let triangle = simplifier.adjacency[a as usize]
+            .iter()
+            .copied()
+            .find(|&t| simplifier.triangles[t].unwrap().contains(&b))
+            .unwrap();
+        let normal = simplifier.normal(simplifier.triangles[triangle].unwrap());
+        let (pa, pb) = (
+            simplifier.positions[a as usize],
+            simplifier.positions[b as usize],
+        );
+        let edge = sub(pb, pa);
+        if let Some(unit) = normalize(cross(edge, normal)) {
+            let quadric = Quadric::plane(unit, pa, dot(edge, edge) * BOUNDARY_WEIGHT);
+            simplifier.quadrics[a as usize].add(&quadric);
+            simplifier.quadrics[b as usize].add(&quadric);
+        }
+    }
+
+    for [a, b] in edges.keys().copied() {
+        simplifier.push(a, b);
+        simplifier.push(b, a);
+    }
+
+    let mut live = simplifier.triangles.len();
+    while live > target {
+        let Collapse {
+            from, to, versions, ..
+        } = match simplifier.heap.pop() {
+            Some(collapse) => collapse,
+            None => break,
+        };
+        let current = [
+            simplifier.versions[from as usize],
+            simplifier.versions[to as usize],
+        ];
+        if versions != current || simplifier.flips(from, to) {
+            continue;
+        }
+        live -= simplifier.collapse(from, to);
+    }
+
+    simplifier
+        .triangles
+        .into_iter()
+        .flatten()
+        .flat_map(|corners| corners.to_vec())
+        .collect()
+}
@@ -0,0 +1,74 @@
+use super::vertex::Vertex;
+
+/// The vertex attributes and indices of a primitive, stored as parallel
+/// arrays.
+///
+/// Attributes that the primitive does not provide are left empty. Vertex
+/// attributes are converted to their floating point (or, for joints, `u16`)
+/// representations.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SoaMesh {
+    /// XYZ vertex positions.
+    pub positions: Vec<[f32; 3]>,
+
+    /// XYZ vertex normals.
+    pub normals: Vec<[f32; 3]>,
+
+    /// XYZW vertex tangents.
+    pub tangents: Vec<[f32; 4]>,
+
+    /// UV texture co-ordinates of set 0.
+    pub tex_coords_0: Vec<[f32; 2]>,
+
+    /// UV texture co-ordinates of set 1.
+    pub tex_coords_1: Vec<[f32; 2]>,
+
+    /// RGBA vertex colors of set 0.
+    pub colors_0: Vec<[f32; 4]>,
+
+    /// Joint indices of set 0.
+    pub joints_0: Vec<[u16; 4]>,
+
+    /// Joint weights of set 0.
+    pub weights_0: Vec<[f32; 4]>,
+
+    /// Vertex indices.
+    pub indices: Vec<u32>,
+}
+
+impl SoaMesh {
+    /// Returns the attributes of the vertex at `index`, or `None` if there is
+    /// no such vertex.
+    pub fn vertex(&self, index: usize) -> Option<Vertex> {
+        Some(Vertex {
+            position: *self.positions.get(index)?,
+            normal: self.normals.get(index).copied(),
+            tangent: self.tangents.get(index).copied(),
+            tex_coords_0: self.tex_coords_0.get(index).copied(),
+            tex_coords_1: self.tex_coords_1.get(index).copied(),
+            color_0: self.colors_0.get(index).copied(),
+            joints_0: self.joints_0.get(index).copied(),
+            weights_0: self.weights_0.get(index).copied(),
+        })
+    }
+
+    /// Appends the attributes of a vertex, leaving attributes that `vertex`
+    /// does not provide untouched.
+    ///
+    /// Indices are not modified.
+    pub fn push_vertex(&mut self, vertex: &Vertex) {
+        self.positions.push(vertex.position);
+        self.normals.extend(vertex.normal);
+        self.tangents.extend(vertex.tangent);
+        self.tex_coords_0.extend(vertex.tex_coords_0);
+        self.tex_coords_1.extend(vertex.tex_coords_1);
+        self.colors_0.extend(vertex.color_0);
+        self.joints_0.extend(vertex.joints_0);
+        self.weights_0.extend(vertex.weights_0);
+    }
+
+    /// Visits the attributes of each vertex in buffer order.
+    pub fn vertices(&self) -> impl Iterator<Item = Vertex> + '_ {
+        (0..self.positions.len()).filter_map(move |index| self.vertex(index))
+    }
+}
@@ -0,0 +1,100 @@
+use crate::mesh::Mode;
+
+/// Expands triangle topologies into a list of triangles.
+///
+/// `indices` is the vertex draw sequence of the primitive. Triangle strips
+/// and fans are expanded such that each triangle keeps the winding order of
+/// the first one, as described in the glTF specification.
+///
+/// Returns `None` if `mode` is not one of `Triangles`, `TriangleStrip`, or
+/// `TriangleFan`.
+pub fn triangles(mode: Mode, indices: &[u32]) -> Option<Vec<[u32; 3]>> {
+    let triangles = match mode {
+        Mode::Triangles => indices
+            .chunks_exact(3)
+            .map(|t| [t[0], t[1], t[2]])
+            .collect(),
+        Mode::TriangleStrip => indices
+            .windows(3)
+            .enumerate()
+            .map(|(i, t)| {
+                if i % 2 == 0 {
+                    [t[0], t[1], t[2]]
+                } else {
+                    [t[1], t[0], t[2]]
+                }
+            })
+            .collect(),
+        Mode::TriangleFan => indices
+            .windows(2)
+            .skip(1)
+            .map(|t| [t[0], t[1], indices[0]])
+            .collect(),
+        _ => return None,
+    };
+    Some(triangles)
+}
+
+/// Expands line topologies into a list of line segments.
+///
+/// `indices` is the vertex draw sequence of the primitive. Line strips and
+/// loops are expanded into individual segments, with loops closed by a final
+/// segment from the last vertex back to the first.
+///
+/// Returns `None` if `mode` is not one of `Lines`, `LineStrip`, or
+/// `LineLoop`.
+pub fn lines(mode: Mode, indices: &[u32]) -> Option<Vec<[u32; 2]>> {
+    let mut lines: Vec<[u32; 2]> = match mode {
+        Mode::Lines => indices.chunks_exact(2).map(|l| [l[0], l[1]]).collect(),
+        Mode::LineStrip | Mode::LineLoop => indices.windows(2).map(|l| [l[0], l[1]]).collect(),
+        _ => return None,
+    };
+    if mode == Mode::LineLoop && indices.len() > 1 {
+        lines.push([indices[indices.len() - 1], indices[0]]);
+    }
+    Some(lines)
+}
+
+/// Reverses the winding order of every triangle in place.
+///
+/// `indices` is the vertex draw sequence of the primitive. Triangle strips
+/// of even length cannot be flipped by reordering alone, so they are prefixed
+/// with a degenerate triangle that shifts the strip alternation by one.
+/// Point and line topologies have no winding and are left unchanged.
+pub fn flip_winding(indices: &mut Vec<u32>, mode: Mode) {
+    match mode {
+        Mode::Triangles => {
+            for triangle in indices.chunks_exact_mut(3) {
+                triangle.swap(1, 2);
+            }
+        }
+        Mode::TriangleStrip if indices.len() % 2 == 1 => indices.reverse(),
+        Mode::TriangleStrip => {
+            if let Some(&first) = indices.first() {
+                indices.insert(0, first);
+            }
+        }
+        Mode::TriangleFan => {
+            if let Some(rest) = indices.get_mut(1..) {
+                rest.reverse();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns `true` if the vertices of `triangle` appear counter-clockwise when
+/// viewed from the side `normal` points towards.
+///
+/// glTF treats counter-clockwise triangles as front-facing.
+pub fn is_counter_clockwise(triangle: [[f32; 3]; 3], normal: [f32; 3]) -> bool {
+    let [a, b, c] = triangle;
+    let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+    let cross = [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ];
+    cross[0] * normal[0] + cross[1] * normal[1] + cross[2] * normal[2] > 0.0
+}
@@ -0,0 +1,60 @@
+/// The vertex attributes of a single vertex of a primitive.
+///
+/// Attributes that the primitive does not provide are `None`. The attributes
+/// correspond to the fields of [`SoaMesh`](super::soa::SoaMesh).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vertex {
+    /// XYZ vertex position.
+    pub position: [f32; 3],
+
+    /// XYZ vertex normal.
+    pub normal: Option<[f32; 3]>,
+
+    /// XYZW vertex tangent.
+    pub tangent: Option<[f32; 4]>,
+
+    /// UV texture co-ordinates of set 0.
+    pub tex_coords_0: Option<[f32; 2]>,
+
+    /// UV texture co-ordinates of set 1.
+    pub tex_coords_1: Option<[f32; 2]>,
+
+    /// RGBA vertex color of set 0.
+    pub color_0: Option<[f32; 4]>,
+
+    /// Joint indices of set 0.
+    pub joints_0: Option<[u16; 4]>,
+
+    /// Joint weights of set 0.
+    pub weights_0: Option<[f32; 4]>,
+}
+
+fn within<T: AsRef<[f32]>>(a: &Option<T>, b: &Option<T>, epsilon: f32) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a
+            .as_ref()
+            .iter()
+            .zip(b.as_ref())
+            .all(|(a, b)| (a - b).abs() <= epsilon),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+impl Vertex {
+    /// Returns `true` if every attribute of `self` differs from that of
+    /// `other` by at most `epsilon` per component.
+    ///
+    /// Joint indices must match exactly, and attributes must be present in
+    /// both vertices or in neither.
+    pub fn approx_eq(&self, other: &Vertex, epsilon: f32) -> bool {
+        within(&Some(self.position), &Some(other.position), epsilon)
+            && within(&self.normal, &other.normal, epsilon)
+            && within(&self.tangent, &other.tangent, epsilon)
+            && within(&self.tex_coords_0, &other.tex_coords_0, epsilon)
+            && within(&self.tex_coords_1, &other.tex_coords_1, epsilon)
+            && within(&self.color_0, &other.color_0, epsilon)
+            && self.joints_0 == other.joints_0
+            && within(&self.weights_0, &other.weights_0, epsilon)
+    }
+}
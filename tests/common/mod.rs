@@ -0,0 +1,167 @@
+//! Helpers for assembling small in-memory glTF assets in tests.
+
+#![allow(dead_code)]
+
+use gltf::json;
+use serde_json::{json, Value};
+
+/// A component type that can be written to a glTF buffer.
+pub trait Component: Copy {
+    /// The corresponding `componentType` constant.
+    const COMPONENT_TYPE: u32;
+
+    /// Appends the little-endian representation of `self`.
+    fn write(self, bytes: &mut Vec<u8>);
+}
+
+macro_rules! impl_component {
+    ($ty:ty, $component_type:expr) => {
+        impl Component for $ty {
+            const COMPONENT_TYPE: u32 = $component_type;
+
+            fn write(self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+impl_component!(i8, json::accessor::BYTE);
+impl_component!(u8, json::accessor::UNSIGNED_BYTE);
+impl_component!(i16, json::accessor::SHORT);
+impl_component!(u16, json::accessor::UNSIGNED_SHORT);
+impl_component!(u32, json::accessor::UNSIGNED_INT);
+impl_component!(f32, json::accessor::FLOAT);
+
+/// Returns the accessor `type` name for elements with `n` components.
+pub fn type_name(n: usize) -> &'static str {
+    match n {
+        1 => "SCALAR",
+        2 => "VEC2",
+        3 => "VEC3",
+        4 => "VEC4",
+        9 => "MAT3",
+        16 => "MAT4",
+        _ => panic!("unsupported element size {}", n),
+    }
+}
+
+/// Flattens `data` into its tightly packed little-endian representation.
+pub fn to_bytes<T: Component, const N: usize>(data: &[[T; N]]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for element in data {
+        for component in element {
+            component.write(&mut bytes);
+        }
+    }
+    bytes
+}
+
+/// Incrementally assembles a glTF asset backed by a single buffer.
+#[derive(Debug, Default)]
+pub struct Builder {
+    /// The buffer contents.
+    pub bin: Vec<u8>,
+
+    /// The accessors created so far.
+    pub accessors: Vec<Value>,
+
+    /// The buffer views created so far.
+    pub views: Vec<Value>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a buffer view holding `bytes`, returning its index.
+    ///
+    /// The buffer is padded to a multiple of four bytes beforehand.
+    pub fn view(&mut self, bytes: &[u8], stride: Option<usize>) -> u32 {
+        self.bin.resize((self.bin.len() + 3) & !3, 0);
+        let mut view = json!({
+            "buffer": 0,
+            "byteOffset": self.bin.len(),
+            "byteLength": bytes.len(),
+        });
+        if let Some(stride) = stride {
+            view["byteStride"] = json!(stride);
+        }
+        self.bin.extend_from_slice(bytes);
+        self.views.push(view);
+        self.views.len() as u32 - 1
+    }
+
+    /// Appends a raw accessor, returning its index.
+    pub fn raw_accessor(&mut self, accessor: Value) -> u32 {
+        self.accessors.push(accessor);
+        self.accessors.len() as u32 - 1
+    }
+
+    /// Appends a tightly packed accessor over a new buffer view.
+    pub fn accessor<T: Component, const N: usize>(&mut self, data: &[[T; N]]) -> u32 {
+        let view = self.view(&to_bytes(data), None);
+        self.raw_accessor(json!({
+            "bufferView": view,
+            "componentType": T::COMPONENT_TYPE,
+            "count": data.len(),
+            "type": type_name(N),
+        }))
+    }
+
+    /// Appends a tightly packed `SCALAR` accessor over a new buffer view.
+    pub fn scalars<T: Component>(&mut self, data: &[T]) -> u32 {
+        let data: Vec<[T; 1]> = data.iter().map(|&x| [x]).collect();
+        self.accessor(&data)
+    }
+
+    /// Appends a `VEC3` position accessor with `min` and `max` set.
+    pub fn positions(&mut self, data: &[[f32; 3]]) -> u32 {
+        let mut min = [f32::MAX; 3];
+        let mut max = [f32::MIN; 3];
+        for p in data {
+            for i in 0..3 {
+                min[i] = min[i].min(p[i]);
+                max[i] = max[i].max(p[i]);
+            }
+        }
+        let index = self.accessor(data);
+        self.accessors[index as usize]["min"] = json!(min);
+        self.accessors[index as usize]["max"] = json!(max);
+        index
+    }
+
+    /// Returns the root JSON object, merged with the top-level properties of
+    /// `extra`.
+    pub fn root_value(&self, extra: Value) -> Value {
+        let mut root = json!({
+            "asset": { "version": "2.0" },
+            "accessors": self.accessors,
+            "bufferViews": self.views,
+            "buffers": [{ "byteLength": self.bin.len() }],
+        });
+        if let Value::Object(map) = extra {
+            for (key, value) in map {
+                root[key] = value;
+            }
+        }
+        root
+    }
+
+    /// Returns the deserialized root object, merged with `extra`.
+    pub fn root(&self, extra: Value) -> json::Root {
+        json::deserialize::from_value(self.root_value(extra)).unwrap()
+    }
+
+    /// Returns an unvalidated document, merged with `extra`.
+    pub fn document(&self, extra: Value) -> gltf::Document {
+        gltf::Document::from_json_without_validation(self.root(extra))
+    }
+
+    /// Returns an unvalidated document containing a single mesh with the
+    /// given primitives.
+    pub fn mesh(&self, primitives: Value) -> gltf::Document {
+        self.document(json!({ "meshes": [{ "primitives": primitives }] }))
+    }
+}
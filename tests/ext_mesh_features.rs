@@ -0,0 +1,25 @@
+#![cfg(feature = "EXT_mesh_features")]
+
+mod common;
+
+use gltf::Semantic;
+use serde_json::json;
+
+#[test]
+fn read_feature_ids() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let feature_ids = builder.scalars(&[7u16, 7, 42]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "_FEATURE_ID_0": feature_ids },
+    }]));
+
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    assert!(primitive.get(&Semantic::FeatureIds(0)).is_some());
+
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let ids: Vec<u32> = reader.read_feature_ids(0).unwrap().into_u32().collect();
+    assert_eq!(ids, [7, 7, 42]);
+    assert!(reader.read_feature_ids(1).is_none());
+}
@@ -0,0 +1,145 @@
+mod common;
+
+use gltf::accessor::Iter;
+use serde_json::json;
+
+#[test]
+fn test_read_mat4_f32() {
+    let matrices = [
+        [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [1.0, 2.0, 3.0, 1.0],
+        ],
+        [
+            [2.0, 0.0, 0.0, 0.0],
+            [0.0, 2.0, 0.0, 0.0],
+            [0.0, 0.0, 2.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+    ];
+    let mut builder = common::Builder::new();
+    let mut bytes = Vec::new();
+    for matrix in &matrices {
+        bytes.extend(common::to_bytes(matrix));
+    }
+    let view = builder.view(&bytes, None);
+    builder.raw_accessor(json!({
+        "bufferView": view, "componentType": 5126, "count": 2, "type": "MAT4",
+    }));
+    let document = builder.document(json!({}));
+    let accessor = document.accessors().next().unwrap();
+    assert_eq!(accessor.size(), 64);
+    let iter = Iter::<[[f32; 4]; 4]>::new(accessor, |_| Some(&builder.bin)).unwrap();
+    assert_eq!(iter.collect::<Vec<_>>(), matrices);
+}
+
+#[test]
+fn test_read_padded_matrix_columns() {
+    let mut builder = common::Builder::new();
+    // Each column of a `MAT2` or `MAT3` of bytes starts on a 4-byte boundary.
+    let mat2 = builder.view(&[1, 2, 0, 0, 3, 4, 0, 0, 5, 6, 0, 0, 7, 8, 0, 0], None);
+    builder.raw_accessor(json!({
+        "bufferView": mat2, "componentType": 5121, "count": 2, "type": "MAT2",
+    }));
+    let mat3 = builder.view(&[1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9, 0], None);
+    builder.raw_accessor(json!({
+        "bufferView": mat3, "componentType": 5121, "count": 1, "type": "MAT3",
+    }));
+    let document = builder.document(json!({}));
+    let mut accessors = document.accessors();
+
+    let accessor = accessors.next().unwrap();
+    assert_eq!(accessor.size(), 8);
+    let iter = Iter::<[[u8; 2]; 2]>::new(accessor, |_| Some(&builder.bin)).unwrap();
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]
+    );
+
+    let accessor = accessors.next().unwrap();
+    assert_eq!(accessor.size(), 12);
+    let iter = Iter::<[[u8; 3]; 3]>::new(accessor, |_| Some(&builder.bin)).unwrap();
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        [[[1, 2, 3], [4, 5, 6], [7, 8, 9]]]
+    );
+}
+
+#[test]
+fn test_read_little_endian_components() {
+    // Reverses the byte order of every `size`-byte component.
+    fn swap_bytes(bytes: &[u8], size: usize) -> Vec<u8> {
+        bytes
+            .chunks_exact(size)
+            .flat_map(|component| component.iter().rev().copied())
+            .collect()
+    }
+
+    let little_endian_u16 = [0x02, 0x01, 0xff, 0x00];
+    let little_endian_f32 = [0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0xc0];
+    let mut builder = common::Builder::new();
+    for bytes in &[
+        little_endian_u16.to_vec(),
+        swap_bytes(&little_endian_u16, 2),
+    ] {
+        let view = builder.view(bytes, None);
+        builder.raw_accessor(json!({
+            "bufferView": view, "componentType": 5123, "count": 2, "type": "SCALAR",
+        }));
+    }
+    let view = builder.view(&little_endian_f32, None);
+    builder.raw_accessor(json!({
+        "bufferView": view, "componentType": 5126, "count": 2, "type": "SCALAR",
+    }));
+    let document = builder.document(json!({}));
+    let mut accessors = document.accessors();
+
+    let read_u16 = |accessor| {
+        Iter::<u16>::new(accessor, |_| Some(&builder.bin))
+            .unwrap()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(read_u16(accessors.next().unwrap()), [0x0102, 0x00ff]);
+    assert_eq!(read_u16(accessors.next().unwrap()), [0x0201, 0xff00]);
+    let floats = Iter::<f32>::new(accessors.next().unwrap(), |_| Some(&builder.bin)).unwrap();
+    assert_eq!(floats.collect::<Vec<_>>(), [1.0, -2.0]);
+}
+
+#[test]
+fn test_f16_to_f32() {
+    use gltf::accessor::util::F16;
+
+    let cases: [(u16, f32); 9] = [
+        (0x0000, 0.0),
+        (0x3c00, 1.0),
+        (0xc000, -2.0),
+        (0x3555, 0.333_251_95),
+        (0x7bff, 65504.0),
+        (0x0400, 6.103_515_6e-5),
+        (0x0001, 5.960_464_5e-8),
+        (0x7c00, f32::INFINITY),
+        (0xfc00, f32::NEG_INFINITY),
+    ];
+    for &(bits, expected) in &cases {
+        assert_eq!(F16(bits).to_f32(), expected, "{:#06x}", bits);
+    }
+    assert!(F16(0x7e00).to_f32().is_nan());
+    assert!(F16(0x8000).to_f32().is_sign_negative());
+}
+
+#[test]
+fn test_read_f16_accessor() {
+    use gltf::accessor::util::F16;
+
+    let mut builder = common::Builder::new();
+    let index = builder.scalars(&[0x3c00u16, 0x3800, 0xbc00]);
+    let document = builder.document(json!({}));
+    let accessor = document.accessors().nth(index as usize).unwrap();
+    let values: Vec<f32> = Iter::<F16>::new(accessor, |_| Some(&builder.bin))
+        .unwrap()
+        .map(f32::from)
+        .collect();
+    assert_eq!(values, [1.0, 0.5, -1.0]);
+}
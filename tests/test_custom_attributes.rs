@@ -0,0 +1,44 @@
+#![cfg(feature = "extras")]
+
+mod common;
+
+use gltf::mesh::util::AttributeData;
+use serde_json::json;
+
+#[test]
+fn test_read_custom_batch_id() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let batch_ids = builder.scalars(&[7u32, 7, 9]);
+    let view = builder.view(&[1, 2, 0, 0, 3, 4, 0, 0], None);
+    let matrix = builder.raw_accessor(json!({
+        "bufferView": view,
+        "componentType": 5121,
+        "count": 1,
+        "type": "MAT2",
+    }));
+    let document = builder.mesh(json!([{
+        "attributes": {
+            "POSITION": positions,
+            "_BATCHID": batch_ids,
+            "_MATRIX": matrix,
+        },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    assert_eq!(
+        reader.read_custom("_BATCHID"),
+        Some(AttributeData::U32(vec![7, 7, 9]))
+    );
+    assert_eq!(
+        reader.read_custom("BATCHID"),
+        reader.read_custom("_BATCHID")
+    );
+    assert_eq!(
+        reader.read_custom("_MATRIX"),
+        Some(AttributeData::U8(vec![1, 2, 3, 4]))
+    );
+    assert!(reader.read_custom("_MISSING").is_none());
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "EXT_accessor_double")]
+
+mod common;
+
+use serde_json::json;
+
+#[test]
+fn test_read_positions_f64() {
+    let positions = [
+        [0.1f64, 1.0e9 + 0.25, -3.0],
+        [6_378_137.125, 0.0, 1.0 / 3.0],
+    ];
+    let single: Vec<[f32; 3]> = positions
+        .iter()
+        .map(|&[x, y, z]| [x as f32, y as f32, z as f32])
+        .collect();
+    let double: Vec<u8> = positions
+        .iter()
+        .flatten()
+        .flat_map(|x| x.to_le_bytes().to_vec())
+        .collect();
+
+    let mut builder = common::Builder::new();
+    let plain = builder.positions(&single);
+    let extended = builder.positions(&single);
+    let view = builder.view(&double, None);
+    builder.accessors[extended as usize]["extensions"] = json!({
+        "EXT_accessor_double": { "bufferView": view },
+    });
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": extended } },
+        { "attributes": { "POSITION": plain } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let read: Vec<[f64; 3]> = reader.read_positions_f64().unwrap().collect();
+    assert_eq!(read, positions);
+    let lossy: Vec<[f32; 3]> = reader.read_positions_f64().unwrap().into_f32().collect();
+    assert_eq!(lossy, single);
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let widened: Vec<[f64; 3]> = reader.read_positions_f64().unwrap().collect();
+    assert_ne!(widened, positions);
+    assert_eq!(widened[1][0], f64::from(single[1][0]));
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use gltf::json;
+use gltf::mesh::util::export::export;
+use gltf::mesh::util::soa::SoaMesh;
+use gltf::mesh::Mode;
+
+#[test]
+fn test_export_round_trip() {
+    let mesh = SoaMesh {
+        positions: vec![[0.0, 0.5, 0.0], [-0.5, -0.5, 0.0], [0.5, -0.5, 0.25]],
+        normals: vec![[0.0, 0.0, 1.0]; 3],
+        tex_coords_0: vec![[0.5, 0.0], [0.0, 1.0], [1.0, 1.0]],
+        joints_0: vec![[0, 1, 0, 0], [1, 0, 0, 0], [2, 0, 0, 0]],
+        weights_0: vec![
+            [0.5, 0.5, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+        ],
+        indices: vec![0, 1, 2],
+        ..SoaMesh::default()
+    };
+    let exported = export(&mesh, Mode::Triangles);
+
+    assert_eq!(exported.accessors.len(), 6);
+    assert_eq!(exported.bin.len() % 4, 0);
+    assert_eq!(exported.buffer.byte_length as usize, exported.bin.len());
+    for view in &exported.views {
+        assert_eq!(view.byte_offset.unwrap() % 4, 0);
+    }
+
+    let bin = exported.bin;
+    let root = json::Root {
+        accessors: exported.accessors,
+        buffers: vec![exported.buffer],
+        buffer_views: exported.views,
+        meshes: vec![json::Mesh {
+            extensions: Default::default(),
+            extras: Default::default(),
+            name: None,
+            primitives: vec![exported.primitive],
+            weights: None,
+        }],
+        ..Default::default()
+    };
+    let document = gltf::Document::from_json(root).unwrap();
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let bounds = primitive.bounding_box();
+    assert_eq!(bounds.min, [-0.5, -0.5, 0.0]);
+    assert_eq!(bounds.max, [0.5, 0.5, 0.25]);
+    assert_eq!(
+        primitive.index_component_type(),
+        Some(gltf::accessor::DataType::U16)
+    );
+
+    let reader = primitive.reader(|_| Some(&bin));
+    assert_eq!(reader.to_soa(), mesh);
+}
+
+#[test]
+fn test_to_obj_fragment_quad() {
+    let positions = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ];
+    let normals = [[0.0f32, 0.0, 1.0]; 4];
+    let tex_coords = [[0.0f32, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]];
+    let indices = [0u16, 1, 2, 0, 2, 3];
+
+    let mut builder = common::Builder::new();
+    let position_index = builder.positions(&positions);
+    let normal_index = builder.accessor(&normals);
+    let tex_coord_index = builder.accessor(&tex_coords);
+    let index_index = builder.scalars(&indices);
+    let attributes = serde_json::json!({
+        "POSITION": position_index,
+        "NORMAL": normal_index,
+        "TEXCOORD_0": tex_coord_index,
+    });
+    let document = builder.mesh(serde_json::json!([
+        { "attributes": attributes, "indices": index_index },
+        { "attributes": attributes, "mode": 1 },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let obj = reader.to_obj_fragment().unwrap();
+
+    let numbers = |line: &str| -> Vec<f32> {
+        line.split_whitespace()
+            .skip(1)
+            .map(|n| n.parse().unwrap())
+            .collect()
+    };
+    let mut read_positions = Vec::new();
+    let mut read_tex_coords = Vec::new();
+    let mut read_normals = Vec::new();
+    let mut faces = Vec::new();
+    for line in obj.lines() {
+        match line.split_whitespace().next() {
+            Some("v") => read_positions.push(numbers(line)),
+            Some("vt") => read_tex_coords.push(numbers(line)),
+            Some("vn") => read_normals.push(numbers(line)),
+            Some("f") => faces.push(
+                line.split_whitespace()
+                    .skip(1)
+                    .map(str::to_owned)
+                    .collect::<Vec<_>>(),
+            ),
+            _ => panic!("unexpected line {:?}", line),
+        }
+    }
+    assert_eq!(faces.len(), 2);
+    for (face, triangle) in faces.iter().zip(indices.chunks_exact(3)) {
+        for (corner, &index) in face.iter().zip(triangle) {
+            let refs: Vec<usize> = corner.split('/').map(|n| n.parse().unwrap()).collect();
+            let index = index as usize;
+            assert_eq!(read_positions[refs[0] - 1], positions[index]);
+            let [u, v] = tex_coords[index];
+            assert_eq!(read_tex_coords[refs[1] - 1], [u, 1.0 - v]);
+            assert_eq!(read_normals[refs[2] - 1], normals[index]);
+        }
+    }
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert!(reader.to_obj_fragment().is_none());
+}
@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+
+use gltf::binary::{ChunkLocation, LazyGlb};
+
+/// A stream that records the furthest position read.
+struct Tracking<'a> {
+    inner: Cursor<&'a [u8]>,
+    furthest: u64,
+}
+
+impl<'a> Read for Tracking<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.furthest = self.furthest.max(self.inner.position());
+        Ok(n)
+    }
+}
+
+impl<'a> Seek for Tracking<'a> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+#[test]
+fn test_lazy_glb_locates_bin() {
+    let json = br#"{"asset":{"version":"2.0"}}"#;
+    let bin = [7u8; 64];
+    let glb = gltf::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Borrowed(&json[..]),
+        bin: Some(Cow::Borrowed(&bin[..])),
+    };
+    let bytes = glb.to_vec().unwrap();
+
+    let mut stream = Tracking {
+        inner: Cursor::new(&bytes[..]),
+        furthest: 0,
+    };
+    let lazy = LazyGlb::from_reader(&mut stream).unwrap();
+    let location = lazy.bin.unwrap();
+    assert_eq!(
+        location,
+        ChunkLocation {
+            offset: bytes.len() as u64 - 64,
+            length: 64,
+        }
+    );
+    assert_eq!(&lazy.json[..json.len()], &json[..]);
+    assert!(stream.furthest <= location.offset);
+
+    assert_eq!(lazy.read_bin(&mut stream).unwrap().unwrap(), &bin[..]);
+}
+
+#[test]
+fn test_lazy_glb_rejects_oversized_json_chunk() {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"glTF");
+    bytes.extend_from_slice(&2u32.to_le_bytes());
+    bytes.extend_from_slice(&28u32.to_le_bytes());
+    bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+    bytes.extend_from_slice(b"JSON");
+    bytes.extend_from_slice(&[b' '; 8]);
+
+    match LazyGlb::from_reader(Cursor::new(&bytes[..])) {
+        Err(gltf::Error::Binary(gltf::binary::Error::ChunkLength {
+            length,
+            length_read,
+            ..
+        })) => {
+            assert_eq!(length, u32::MAX);
+            assert_eq!(length_read, 8);
+        }
+        other => panic!("expected a chunk length error, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use gltf::mesh::util::merge::{merge, MergeError, TexCoordPolicy};
+use serde_json::json;
+
+#[test]
+fn test_merge_remaps_tex_coords() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let set_a = builder.accessor(&[[0.0f32, 0.0]; 3]);
+    let set_b = builder.accessor(&[[1.0f32, 1.0]; 3]);
+    let document = builder.document(json!({
+        "images": [{ "uri": "texture.png" }],
+        "textures": [{ "source": 0 }],
+        "materials": [
+            { "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } } },
+            { "pbrMetallicRoughness": { "baseColorTexture": { "index": 0, "texCoord": 1 } } },
+        ],
+        "meshes": [{
+            "primitives": [
+                {
+                    "attributes": { "POSITION": positions, "TEXCOORD_0": set_a },
+                    "material": 0,
+                },
+                {
+                    "attributes": { "POSITION": positions, "TEXCOORD_1": set_b },
+                    "material": 1,
+                },
+            ],
+        }],
+    }));
+    let mesh = document.meshes().next().unwrap();
+    let primitives: Vec<_> = mesh.primitives().collect();
+    let first = primitives[0].reader(|_| Some(&builder.bin)).to_soa();
+    let second = primitives[1].reader(|_| Some(&builder.bin)).to_soa();
+    let first_material = primitives[0].material();
+    let second_material = primitives[1].material();
+    assert_eq!(second_material.tex_coord_sets(), [1]);
+
+    assert_eq!(
+        merge(
+            &first,
+            &first_material,
+            &second,
+            &second_material,
+            TexCoordPolicy::Error,
+        ),
+        Err(MergeError::TexCoordConflict {
+            first: vec![0],
+            second: vec![1],
+        })
+    );
+
+    let merged = merge(
+        &first,
+        &first_material,
+        &second,
+        &second_material,
+        TexCoordPolicy::Remap,
+    )
+    .unwrap();
+    assert_eq!(merged.positions.len(), 6);
+    assert_eq!(
+        merged.tex_coords_0,
+        [
+            [0.0, 0.0],
+            [0.0, 0.0],
+            [0.0, 0.0],
+            [1.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 1.0]
+        ]
+    );
+    assert!(merged.tex_coords_1.is_empty());
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use gltf::animation::util::ReadOutputs;
+use serde_json::json;
+
+/// Returns the rotations of a single channel animating `output`.
+fn rotations(builder: &mut common::Builder, output: u32) -> gltf::Document {
+    let input = builder.scalars(&[0.0f32, 1.0, 2.0]);
+    builder.accessors[output as usize]["normalized"] = json!(true);
+    builder.document(json!({
+        "nodes": [{}],
+        "animations": [{
+            "channels": [{ "sampler": 0, "target": { "node": 0, "path": "rotation" } }],
+            "samplers": [{ "input": input, "output": output }],
+        }],
+    }))
+}
+
+#[test]
+fn test_signed_normalized_rotations_saturate() {
+    let mut builder = common::Builder::new();
+    let output = builder.accessor(&[
+        [i16::MIN, -i16::MAX, 0, i16::MAX],
+        [-1, 1, -16384, 16384],
+        [0, 0, 0, i16::MAX],
+    ]);
+    let document = rotations(&mut builder, output);
+    let channel = document
+        .animations()
+        .next()
+        .unwrap()
+        .channels()
+        .next()
+        .unwrap();
+    let reader = channel.reader(|_| Some(&builder.bin));
+    let read = || match reader.read_outputs() {
+        Some(ReadOutputs::Rotations(rotations)) => rotations,
+        _ => panic!("expected rotations"),
+    };
+
+    let floats: Vec<[f32; 4]> = read().into_f32().collect();
+    assert_eq!(floats[0], [-1.0, -1.0, 0.0, 1.0]);
+    assert_eq!(floats[1][0], -1.0 / 32767.0);
+    assert_eq!(floats[1][2], -16384.0 / 32767.0);
+
+    let bytes: Vec<[i8; 4]> = read().into_i8().collect();
+    assert_eq!(bytes[0], [-127, -127, 0, 127]);
+    assert_eq!(bytes[1], [0, 0, -64, 64]);
+
+    let unsigned: Vec<[u8; 4]> = read().into_u8().collect();
+    assert_eq!(unsigned[0], [0, 0, 0, 255]);
+    assert_eq!(unsigned[1], [0, 0, 0, 128]);
+}
+
+#[test]
+fn test_normalized_integer_widening() {
+    let mut builder = common::Builder::new();
+    let output = builder.accessor(&[[i8::MIN, -i8::MAX, 0, i8::MAX], [0; 4], [0, 0, 0, 127]]);
+    let document = rotations(&mut builder, output);
+    let channel = document
+        .animations()
+        .next()
+        .unwrap()
+        .channels()
+        .next()
+        .unwrap();
+    let reader = channel.reader(|_| Some(&builder.bin));
+    let read = || match reader.read_outputs() {
+        Some(ReadOutputs::Rotations(rotations)) => rotations,
+        _ => panic!("expected rotations"),
+    };
+
+    let shorts: Vec<[i16; 4]> = read().into_i16().collect();
+    assert_eq!(shorts[0], [-32767, -32767, 0, 32767]);
+    let unsigned: Vec<[u16; 4]> = read().into_u16().collect();
+    assert_eq!(unsigned[0], [0, 0, 0, 65535]);
+}
+
+#[test]
+fn test_normalized_colors_clamp() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 2]);
+    let colors = builder.accessor(&[[0.0f32, 0.5, 1.0, 1.0], [-0.5, 1.5, 0.25, 0.75]]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "COLOR_0": colors },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let bytes: Vec<[u8; 4]> = reader.read_colors(0).unwrap().into_rgba_u8().collect();
+    assert_eq!(bytes, [[0, 128, 255, 255], [0, 255, 64, 191]]);
+    let shorts: Vec<[u16; 4]> = reader.read_colors(0).unwrap().into_rgba_u16().collect();
+    assert_eq!(shorts, [[0, 32768, 65535, 65535], [0, 65535, 16384, 49151]]);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use gltf::mesh::util::optimize::{
+    average_cache_miss_ratio, optimize_vertex_cache, optimize_vertex_fetch,
+};
+use serde_json::json;
+
+fn sorted_triangles(indices: &[u32]) -> Vec<[u32; 3]> {
+    let mut triangles: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]])
+        .collect();
+    triangles.sort_unstable();
+    triangles
+}
+
+/// Returns the triangle list of an `n` by `n` grid, with the rows of quads
+/// in a scrambled order.
+fn grid(n: u32) -> Vec<u32> {
+    let mut indices = Vec::new();
+    for row in 0..n {
+        let y = (row * 7) % n;
+        for x in 0..n {
+            let v00 = y * (n + 1) + x;
+            let (v10, v01, v11) = (v00 + 1, v00 + n + 1, v00 + n + 2);
+            indices.extend_from_slice(&[v00, v10, v11, v00, v11, v01]);
+        }
+    }
+    indices
+}
+
+#[test]
+fn test_optimize_vertex_cache_permutes_triangles() {
+    let original = grid(16);
+    let mut optimized = original.clone();
+    optimize_vertex_cache(&mut optimized);
+    assert_eq!(optimized.len(), original.len());
+    assert_eq!(sorted_triangles(&optimized), sorted_triangles(&original));
+
+    let mut trailing = vec![0, 1, 2, 2, 1, 3, 7];
+    optimize_vertex_cache(&mut trailing);
+    assert_eq!(trailing[6], 7);
+    assert_eq!(sorted_triangles(&trailing), [[0, 1, 2], [2, 1, 3]]);
+
+    let mut empty: Vec<u32> = Vec::new();
+    optimize_vertex_cache(&mut empty);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_optimize_vertex_cache_with_large_indices() {
+    // Allocating per vertex up to the largest index would run out of memory.
+    let mut indices = vec![0, 1, u32::MAX, u32::MAX, 1, u32::MAX - 1];
+    optimize_vertex_cache(&mut indices);
+    assert_eq!(
+        sorted_triangles(&indices),
+        [[0, 1, u32::MAX], [u32::MAX, 1, u32::MAX - 1]]
+    );
+}
+
+#[test]
+fn test_optimize_vertex_fetch() {
+    let positions = [
+        [0.0, 0.0, 0.0],
+        [9.0, 9.0, 9.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [1.0, 0.0, 0.0],
+    ];
+    let normals: Vec<[f32; 3]> = (0..5).map(|i| [0.0, 0.0, i as f32]).collect();
+    let original_indices = [3u32, 0, 2, 2, 0, 4];
+
+    let mut indices = original_indices;
+    assert_eq!(optimize_vertex_fetch(&mut indices), [3, 0, 2, 4]);
+    assert_eq!(indices, [0, 1, 2, 2, 1, 3]);
+
+    let mut builder = common::Builder::new();
+    let position_index = builder.positions(&positions);
+    let normal_index = builder.accessor(&normals);
+    let index_index = builder.scalars(&original_indices);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": position_index, "NORMAL": normal_index },
+        "indices": index_index,
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let (vertices, indices) = reader.optimize_vertex_fetch();
+    assert_eq!(vertices.len(), 4);
+    let drawn: Vec<_> = indices
+        .iter()
+        .map(|&i| (vertices[i as usize].position, vertices[i as usize].normal))
+        .collect();
+    let expected: Vec<_> = original_indices
+        .iter()
+        .map(|&i| (positions[i as usize], Some(normals[i as usize])))
+        .collect();
+    assert_eq!(drawn, expected);
+}
+
+#[test]
+fn test_vertex_cache_score() {
+    // Four triangles in strip order transform each of the six vertices once.
+    let strip_order = [0, 1, 2, 2, 1, 3, 2, 3, 4, 4, 3, 5];
+    assert_eq!(average_cache_miss_ratio(&strip_order, 16), 1.5);
+    assert_eq!(average_cache_miss_ratio(&strip_order, 0), 3.0);
+    assert_eq!(average_cache_miss_ratio(&[], 16), 0.0);
+
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 6]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions }, "mode": 5 },
+        { "attributes": { "POSITION": positions }, "mode": 0 },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(reader.vertex_cache_score(16), 1.5);
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(reader.vertex_cache_score(16), 0.0);
+}
@@ -0,0 +1,439 @@
+mod common;
+
+use gltf::Semantic;
+use serde_json::json;
+
+#[test]
+fn test_overlapping_attributes() {
+    let mut builder = common::Builder::new();
+    let view = builder.view(&[0; 48], None);
+    // NORMAL starts halfway through the POSITION data.
+    let positions = builder.raw_accessor(json!({
+        "bufferView": view, "componentType": 5126, "count": 3, "type": "VEC3",
+    }));
+    let normals = builder.raw_accessor(json!({
+        "bufferView": view, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC3",
+    }));
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "NORMAL": normals },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    assert_eq!(
+        primitive.overlapping_attributes(),
+        [(Semantic::Normals, Semantic::Positions)]
+    );
+}
+
+#[test]
+fn test_interleaved_attributes_do_not_overlap() {
+    let mut builder = common::Builder::new();
+    let view = builder.view(&[0; 72], Some(24));
+    let positions = builder.raw_accessor(json!({
+        "bufferView": view, "componentType": 5126, "count": 3, "type": "VEC3",
+    }));
+    let normals = builder.raw_accessor(json!({
+        "bufferView": view, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC3",
+    }));
+    let separate = builder.accessor(&[[0.0f32; 2]; 3]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "NORMAL": normals, "TEXCOORD_0": separate },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    assert!(primitive.overlapping_attributes().is_empty());
+}
+
+#[test]
+fn test_aabb_iter_scans_without_min_max() {
+    let data = [[1.0, -2.0, 0.5], [-1.0, 4.0, 0.0], [0.0, 1.0, -3.0]];
+    let mut builder = common::Builder::new();
+    let positions = builder.accessor(&data);
+    let document = builder.mesh(json!([{ "attributes": { "POSITION": positions } }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+
+    assert!(primitive.try_bounding_box().is_none());
+    let bounds = primitive.aabb_iter(|_| Some(&builder.bin)).unwrap();
+    assert_eq!(bounds.min, [-1.0, -2.0, -3.0]);
+    assert_eq!(bounds.max, [1.0, 4.0, 0.5]);
+}
+
+#[test]
+fn test_aabb_iter_prefers_metadata() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 2.0, 3.0]]);
+    builder.accessors[positions as usize]["max"] = json!([10.0, 10.0, 10.0]);
+    let document = builder.mesh(json!([{ "attributes": { "POSITION": positions } }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+
+    let bounds = primitive.aabb_iter(|_| Some(&builder.bin)).unwrap();
+    assert_eq!(bounds.max, [10.0, 10.0, 10.0]);
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(reader.compute_bounding_box().unwrap().max, [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_bounding_sphere_of_cube() {
+    let mut corners = Vec::new();
+    for &x in &[1.0, 3.0] {
+        for &y in &[-1.0, 1.0] {
+            for &z in &[-1.0, 1.0] {
+                corners.push([x, y, z]);
+            }
+        }
+    }
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&corners);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions } },
+        { "attributes": { "NORMAL": positions } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let (center, radius) = reader.bounding_sphere().unwrap();
+    for corner in &corners {
+        let distance = (0..3)
+            .map(|i| (corner[i] - center[i]).powi(2))
+            .sum::<f32>()
+            .sqrt();
+        assert!(distance <= radius);
+    }
+    // Opposite corners of the cube span the smallest enclosing sphere.
+    let optimal = 3.0f32.sqrt();
+    assert!(radius >= optimal && radius < optimal * 1.05);
+    for (c, expected) in center.iter().zip(&[2.0, 0.0, 0.0]) {
+        assert!((c - expected).abs() < 0.1);
+    }
+
+    let primitive = primitives.next().unwrap();
+    assert!(primitive
+        .reader(|_| Some(&builder.bin))
+        .bounding_sphere()
+        .is_none());
+}
+
+#[test]
+fn test_is_skinned_and_is_morphed() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let joints = builder.accessor(&[[0u8; 4]; 3]);
+    let weights = builder.accessor(&[[1.0f32, 0.0, 0.0, 0.0]; 3]);
+    let skinned = json!({ "POSITION": positions, "JOINTS_0": joints, "WEIGHTS_0": weights });
+    let targets = json!([{ "POSITION": positions }]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions } },
+        { "attributes": skinned },
+        { "attributes": { "POSITION": positions }, "targets": targets },
+        { "attributes": skinned, "targets": targets },
+        { "attributes": { "POSITION": positions, "JOINTS_0": joints } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let flags: Vec<(bool, bool)> = mesh
+        .primitives()
+        .map(|primitive| (primitive.is_skinned(), primitive.is_morphed()))
+        .collect();
+    assert_eq!(
+        flags,
+        [
+            (false, false),
+            (true, false),
+            (false, true),
+            (true, true),
+            (false, false),
+        ]
+    );
+}
+
+#[test]
+fn test_attribute_mask() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let normals = builder.accessor(&[[0.0f32, 0.0, 1.0]; 3]);
+    let tex_coords = builder.accessor(&[[0.0f32, 0.0]; 3]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "NORMAL": normals, "TEXCOORD_0": tex_coords },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    assert_eq!(primitive.attribute_mask(), 0b1_0011);
+}
+
+#[test]
+fn test_mesh_bounding_box() {
+    let mut builder = common::Builder::new();
+    let a = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]]);
+    let b = builder.positions(&[[-2.0, 0.5, 0.0], [0.0, 3.0, 0.5]]);
+    let unbounded = builder.accessor(&[[10.0f32, 10.0, 10.0]]);
+    let document = builder.document(json!({
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": a } },
+                    { "attributes": { "POSITION": b } },
+                    { "attributes": { "POSITION": unbounded } },
+                ],
+            },
+            { "primitives": [{ "attributes": { "POSITION": unbounded } }] },
+        ],
+    }));
+    let mut meshes = document.meshes();
+    let bounds = meshes.next().unwrap().bounding_box().unwrap();
+    assert_eq!(bounds.min, [-2.0, 0.0, 0.0]);
+    assert_eq!(bounds.max, [1.0, 3.0, 1.0]);
+    assert!(meshes.next().unwrap().bounding_box().is_none());
+}
+
+#[test]
+fn test_byte_size() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 4]);
+    let tex_coords = builder.accessor(&[[0u16; 2]; 4]);
+    let indices = builder.scalars(&[0u16, 1, 2, 0, 2, 3]);
+    let displacements = builder.accessor(&[[0.0f32; 3]; 4]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "TEXCOORD_0": tex_coords },
+        "indices": indices,
+        "targets": [{ "POSITION": displacements }],
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    assert_eq!(primitive.byte_size(), 48 + 16 + 12 + 48);
+}
+
+#[test]
+fn test_weighted_morph_targets() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let first = builder.accessor(&[[1.0f32, 0.0, 0.0]; 3]);
+    let second = builder.accessor(&[[0.0f32, 1.0, 0.0]; 3]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions },
+        "targets": [{ "POSITION": first }, { "POSITION": second }],
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+
+    let pairs: Vec<(f32, usize)> = primitive
+        .weighted_morph_targets(&[0.25, 0.75])
+        .unwrap()
+        .map(|(weight, target)| (weight, target.positions().unwrap().index()))
+        .collect();
+    assert_eq!(pairs, [(0.25, first as usize), (0.75, second as usize)]);
+    assert!(primitive.weighted_morph_targets(&[1.0]).is_none());
+}
+
+#[test]
+fn test_visit_primitives() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let primitive = json!({ "attributes": { "POSITION": positions } });
+    let document = builder.document(json!({
+        "meshes": [
+            { "primitives": [primitive, primitive] },
+            { "primitives": [primitive] },
+            { "primitives": [primitive, primitive, primitive] },
+        ],
+    }));
+
+    let mut visited = Vec::new();
+    document.visit_primitives(|mesh, primitive| visited.push((mesh.index(), primitive.index())));
+    let expected: Vec<(usize, usize)> = document
+        .meshes()
+        .flat_map(|mesh| {
+            let index = mesh.index();
+            mesh.primitives()
+                .map(move |primitive| (index, primitive.index()))
+        })
+        .collect();
+    assert_eq!(visited.len(), 6);
+    assert_eq!(visited, expected);
+}
+
+#[test]
+fn test_index_count_and_component_type() {
+    use gltf::accessor::DataType;
+
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 4]);
+    let short = builder.scalars(&[0u16, 1, 2]);
+    let int = builder.scalars(&[0u32, 1, 2, 0, 2, 3]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions }, "indices": short },
+        { "attributes": { "POSITION": positions }, "indices": int },
+        { "attributes": { "POSITION": positions } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let indices: Vec<_> = mesh
+        .primitives()
+        .map(|primitive| (primitive.index_count(), primitive.index_component_type()))
+        .collect();
+    assert_eq!(
+        indices,
+        [
+            (Some(3), Some(DataType::U16)),
+            (Some(6), Some(DataType::U32)),
+            (None, None),
+        ]
+    );
+}
+
+#[test]
+fn test_draw_call_params() {
+    use gltf::accessor::DataType;
+    use gltf::mesh::DrawCall;
+
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 4]);
+    let view = builder.view(&common::to_bytes(&[[9u16], [0], [1], [2]]), None);
+    let indices = builder.raw_accessor(json!({
+        "bufferView": view,
+        "byteOffset": 2,
+        "componentType": 5123,
+        "count": 3,
+        "type": "SCALAR",
+    }));
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions }, "indices": indices },
+        { "attributes": { "POSITION": positions }, "mode": 3 },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    assert_eq!(
+        primitives.next().unwrap().draw_call_params(),
+        DrawCall {
+            mode_gl: gltf::json::mesh::TRIANGLES,
+            count: 3,
+            indexed: true,
+            index_type: Some(DataType::U16),
+            offset: 48 + 2,
+        }
+    );
+    assert_eq!(
+        primitives.next().unwrap().draw_call_params(),
+        DrawCall {
+            mode_gl: gltf::json::mesh::LINE_STRIP,
+            count: 4,
+            indexed: false,
+            index_type: None,
+            offset: 0,
+        }
+    );
+}
+
+#[test]
+fn test_primitives_mut_edits_material() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let json = builder.root(json!({
+        "materials": [{}, {}],
+        "meshes": [{
+            "primitives": [{ "attributes": { "POSITION": positions }, "material": 0 }],
+        }],
+    }));
+
+    let mut edited = json.clone();
+    let primitive = &mut edited.meshes[0].primitives_mut()[0];
+    primitive.material = Some(gltf::json::Index::new(1));
+    let document = gltf::Document::from_json(edited).unwrap();
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    assert_eq!(primitive.material().index(), Some(1));
+
+    let mut edited = json;
+    edited.meshes[0].primitives_mut()[0].material = Some(gltf::json::Index::new(2));
+    assert!(gltf::Document::from_json(edited).is_err());
+}
+
+#[test]
+fn test_indices_fit_u16() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    for count in [65535, 65536] {
+        builder.raw_accessor(json!({
+            "bufferView": 0,
+            "componentType": 5126,
+            "count": count,
+            "type": "VEC3",
+        }));
+    }
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions } },
+        { "attributes": { "POSITION": 1 } },
+        { "attributes": { "POSITION": 2 } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let fits: Vec<bool> = mesh.primitives().map(|p| p.indices_fit_u16()).collect();
+    assert_eq!(fits, [true, true, false]);
+}
+
+#[test]
+fn test_shares_vertices_with() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 4]);
+    let normals = builder.accessor(&[[0.0f32, 0.0, 1.0]; 4]);
+    let first = builder.scalars(&[0u16, 1, 2]);
+    let second = builder.scalars(&[2u16, 1, 3]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions, "NORMAL": normals }, "indices": first },
+        { "attributes": { "NORMAL": normals, "POSITION": positions }, "indices": second },
+        { "attributes": { "POSITION": positions }, "indices": second },
+        {
+            "attributes": { "POSITION": positions, "NORMAL": normals },
+            "targets": [{ "POSITION": positions }],
+        },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let primitives: Vec<_> = mesh.primitives().collect();
+
+    assert!(primitives[0].shares_vertices_with(&primitives[1]));
+    assert!(primitives[1].shares_vertices_with(&primitives[0]));
+    assert!(!primitives[0].shares_vertices_with(&primitives[2]));
+    assert!(!primitives[0].shares_vertices_with(&primitives[3]));
+}
+
+#[test]
+fn test_diff() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let moved = builder.positions(&[[1.0; 3]; 3]);
+    let normals = builder.accessor(&[[0.0f32, 0.0, 1.0]; 3]);
+    let indices = builder.scalars(&[0u16, 1, 2]);
+    let document = builder.document(json!({
+        "materials": [{}, {}],
+        "meshes": [{
+            "primitives": [
+                { "attributes": { "POSITION": positions }, "indices": indices, "material": 0 },
+                { "attributes": { "POSITION": positions }, "indices": indices, "material": 1 },
+                { "attributes": { "POSITION": moved, "NORMAL": normals }, "mode": 0 },
+            ],
+        }],
+    }));
+    let mesh = document.meshes().next().unwrap();
+    let primitives: Vec<_> = mesh.primitives().collect();
+
+    let diff = primitives[0].diff(&primitives[1]);
+    assert_eq!(
+        diff,
+        gltf::mesh::PrimitiveDiff {
+            material: true,
+            ..Default::default()
+        }
+    );
+    assert!(primitives[0].diff(&primitives[0]).is_empty());
+
+    let diff = primitives[0].diff(&primitives[2]);
+    assert_eq!(diff.added, [Semantic::Normals]);
+    assert!(diff.removed.is_empty());
+    assert_eq!(diff.changed, [Semantic::Positions]);
+    assert!(diff.indices && diff.material && diff.mode);
+    assert_eq!(
+        primitives[2].diff(&primitives[0]).removed,
+        [Semantic::Normals]
+    );
+}
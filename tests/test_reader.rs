@@ -0,0 +1,1070 @@
+mod common;
+
+use serde_json::json;
+
+#[test]
+fn test_positions_bytes_match_reader() {
+    let positions = [[0.0, 0.25, 0.5], [1.0, -1.0, 2.0], [3.5, 0.0, -0.75]];
+    let mut builder = common::Builder::new();
+    let index = builder.positions(&positions);
+    let document = builder.mesh(json!([{ "attributes": { "POSITION": index } }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let bytes = reader.positions_bytes().unwrap();
+    assert_eq!(bytes.len(), positions.len() * 12);
+    let from_bytes: Vec<[f32; 3]> = bytes
+        .chunks_exact(12)
+        .map(|chunk| {
+            let component =
+                |i: usize| f32::from_le_bytes([chunk[i], chunk[i + 1], chunk[i + 2], chunk[i + 3]]);
+            [component(0), component(4), component(8)]
+        })
+        .collect();
+    let eager: Vec<[f32; 3]> = reader.read_positions().unwrap().collect();
+    assert_eq!(from_bytes, eager);
+}
+
+#[test]
+fn test_positions_bytes_interleaved() {
+    let mut builder = common::Builder::new();
+    let bytes = common::to_bytes(&[[0.0f32; 4]; 3]);
+    let view = builder.view(&bytes, Some(16));
+    let index = builder.raw_accessor(json!({
+        "bufferView": view,
+        "componentType": 5126,
+        "count": 3,
+        "type": "VEC3",
+    }));
+    let document = builder.mesh(json!([{ "attributes": { "POSITION": index } }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert!(reader.positions_bytes().is_none());
+    assert_eq!(reader.read_positions().unwrap().count(), 3);
+}
+
+#[test]
+fn test_reader_with_resolver() {
+    use std::cell::RefCell;
+
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let indices_bin = common::to_bytes(&[[2u16], [1], [0]]);
+    builder
+        .views
+        .push(json!({ "buffer": 1, "byteLength": indices_bin.len() }));
+    let indices = builder.raw_accessor(json!({
+        "bufferView": builder.views.len() - 1,
+        "componentType": 5123,
+        "count": 3,
+        "type": "SCALAR",
+    }));
+    let document = builder.document(json!({
+        "buffers": [
+            { "byteLength": builder.bin.len() },
+            { "byteLength": indices_bin.len() },
+        ],
+        "meshes": [{
+            "primitives": [{ "attributes": { "POSITION": positions }, "indices": indices }],
+        }],
+    }));
+    let buffers = [builder.bin.as_slice(), indices_bin.as_slice()];
+
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let requested = RefCell::new(Vec::new());
+    let reader = primitive.reader_with_resolver(|index| {
+        requested.borrow_mut().push(index);
+        buffers.get(index).copied()
+    });
+    assert!(requested.borrow().is_empty());
+
+    let indices: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+    assert_eq!(indices, [2, 1, 0]);
+    assert_eq!(*requested.borrow(), [1]);
+
+    assert_eq!(reader.read_positions().unwrap().count(), 3);
+    assert_eq!(*requested.borrow(), [1, 0]);
+}
+
+#[test]
+fn test_triangles_indexed_quad() {
+    let corners = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ];
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&corners);
+    let indices = builder.scalars(&[0u16, 1, 2, 0, 2, 3]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions },
+        "indices": indices,
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(
+        reader.triangles().unwrap(),
+        [
+            [corners[0], corners[1], corners[2]],
+            [corners[0], corners[2], corners[3]],
+        ]
+    );
+}
+
+#[test]
+fn test_triangles_strip_and_fan() {
+    use gltf::mesh::util::topology::triangles;
+    use gltf::mesh::Mode;
+
+    assert_eq!(
+        triangles(Mode::TriangleStrip, &[0, 1, 2, 3]).unwrap(),
+        [[0, 1, 2], [2, 1, 3]]
+    );
+    assert_eq!(
+        triangles(Mode::TriangleFan, &[0, 1, 2, 3]).unwrap(),
+        [[1, 2, 0], [2, 3, 0]]
+    );
+    assert!(triangles(Mode::Lines, &[0, 1]).is_none());
+}
+
+#[test]
+fn test_indexed_positions() {
+    let corners = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]];
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&corners);
+    let indices = builder.scalars(&[2u8, 0, 1, 2]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions },
+        "indices": indices,
+        "mode": 3,
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(
+        reader.indexed_positions().unwrap().collect::<Vec<_>>(),
+        [
+            (2, corners[2]),
+            (0, corners[0]),
+            (1, corners[1]),
+            (2, corners[2]),
+        ]
+    );
+}
+
+#[test]
+fn test_to_soa() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let tex_coords = builder.accessor(&[[0u8, 0], [255, 0], [0, 255]]);
+    builder.accessors[tex_coords as usize]["normalized"] = json!(true);
+    let indices = builder.scalars(&[0u16, 1, 2]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "TEXCOORD_0": tex_coords },
+        "indices": indices,
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let soa = reader.to_soa();
+
+    assert_eq!(
+        soa.positions,
+        reader.read_positions().unwrap().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        soa.tex_coords_0,
+        reader
+            .read_tex_coords(0)
+            .unwrap()
+            .into_f32()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(soa.tex_coords_0[1], [1.0, 0.0]);
+    assert_eq!(
+        soa.indices,
+        reader
+            .read_indices()
+            .unwrap()
+            .into_u32()
+            .collect::<Vec<_>>()
+    );
+    assert!(soa.normals.is_empty());
+    assert!(soa.tex_coords_1.is_empty());
+    assert!(soa.joints_0.is_empty());
+}
+
+#[test]
+fn test_read_normals_normalized() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let normals = builder.accessor(&[[0.0, 0.0, 2.0], [0.0, 0.5, 0.0], [0.0, 0.0, 0.0]]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "NORMAL": normals },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    assert!(reader.has_non_unit_normals(1e-3));
+    assert_eq!(
+        reader.read_normals_normalized().unwrap(),
+        [[0.0, 0.0, 1.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]]
+    );
+}
+
+#[test]
+fn test_content_hash_ignores_attribute_order() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let normals = builder.accessor(&[[0.0, 0.0, 1.0]; 3]);
+    let other_positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let other_normals = builder.accessor(&[[0.0, 0.0, 1.0]; 3]);
+    let flipped_normals = builder.accessor(&[[0.0, 0.0, -1.0]; 3]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions, "NORMAL": normals } },
+        { "attributes": { "NORMAL": other_normals, "POSITION": other_positions } },
+        { "attributes": { "POSITION": positions, "NORMAL": flipped_normals } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let hashes: Vec<u64> = mesh
+        .primitives()
+        .map(|primitive| primitive.reader(|_| Some(&builder.bin)).content_hash())
+        .collect();
+    assert_eq!(hashes[0], hashes[1]);
+    assert_ne!(hashes[0], hashes[2]);
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_verify_checksum() {
+    use gltf::mesh::ChecksumStatus;
+
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let indices = builder.scalars(&[0u16, 1, 2]);
+    let primitive = json!({ "attributes": { "POSITION": positions }, "indices": indices });
+    let checksum = {
+        let document = builder.mesh(json!([primitive]));
+        let mesh = document.meshes().next().unwrap();
+        let primitive = mesh.primitives().next().unwrap();
+        let reader = primitive.reader(|_| Some(&builder.bin));
+        assert_eq!(reader.verify_checksum(), None);
+        reader.data_checksum()
+    };
+
+    let mut stored = primitive.clone();
+    stored["extras"] = json!({ "checksum": checksum });
+    let mut hex = primitive;
+    hex["extras"] = json!({ "checksum": format!("{:016x}", checksum) });
+    let document = builder.mesh(json!([stored, hex]));
+    let mesh = document.meshes().next().unwrap();
+    for primitive in mesh.primitives() {
+        let reader = primitive.reader(|_| Some(&builder.bin));
+        assert_eq!(reader.verify_checksum(), Some(ChecksumStatus::Match));
+
+        let mut corrupted = builder.bin.clone();
+        corrupted[4] ^= 0x01;
+        let reader = primitive.reader(|_| Some(&corrupted));
+        assert_eq!(reader.verify_checksum(), Some(ChecksumStatus::Mismatch));
+
+        let reader = primitive.reader(|_| None);
+        assert_eq!(reader.verify_checksum(), Some(ChecksumStatus::Unresolved));
+    }
+}
+
+#[test]
+fn test_flip_winding() {
+    use gltf::mesh::util::topology::{flip_winding, is_counter_clockwise, triangles};
+    use gltf::mesh::Mode;
+
+    let corners = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    let up = [0.0, 0.0, 1.0];
+    let mut indices = vec![0, 1, 2];
+    assert!(is_counter_clockwise(corners, up));
+    flip_winding(&mut indices, Mode::Triangles);
+    assert_eq!(indices, [0, 2, 1]);
+    let flipped = [corners[0], corners[2], corners[1]];
+    assert!(!is_counter_clockwise(flipped, up));
+
+    // Every triangle of a flipped strip or fan is the reverse of the original.
+    let reversed = |t: [u32; 3]| [t[0], t[2], t[1]];
+    let same_winding =
+        |a: [u32; 3], b: [u32; 3]| a == b || a == [b[1], b[2], b[0]] || a == [b[2], b[0], b[1]];
+    for &(mode, len) in &[
+        (Mode::TriangleStrip, 5),
+        (Mode::TriangleStrip, 6),
+        (Mode::TriangleFan, 6),
+    ] {
+        let mut indices: Vec<u32> = (0..len).collect();
+        let original = triangles(mode, &indices).unwrap();
+        flip_winding(&mut indices, mode);
+        let mut flipped = triangles(mode, &indices).unwrap();
+        flipped.retain(|t| t[0] != t[1] && t[1] != t[2] && t[0] != t[2]);
+        assert_eq!(original.len(), flipped.len());
+        for triangle in original {
+            let triangle = reversed(triangle);
+            assert!(flipped.iter().any(|&t| same_winding(t, triangle)));
+        }
+    }
+}
+
+#[test]
+fn test_weld_vertices() {
+    // Two triangles of a quad with unshared vertices, one slightly perturbed.
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0],
+        [1.0, 1.0001, 0.0],
+        [0.0, 1.0, 0.0],
+    ]);
+    let normals = builder.accessor(&[[0.0f32, 0.0, 1.0]; 6]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "NORMAL": normals },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let (vertices, indices) = reader.weld_vertices(1e-3);
+    assert_eq!(vertices.len(), 4);
+    assert_eq!(indices, [0, 1, 2, 0, 2, 3]);
+    assert_eq!(vertices[3].position, [0.0, 1.0, 0.0]);
+    assert_eq!(vertices[3].normal, Some([0.0, 0.0, 1.0]));
+
+    let (vertices, _) = reader.weld_vertices(0.0);
+    assert_eq!(vertices.len(), 5);
+}
+
+#[test]
+fn test_to_indexed_quad() {
+    let corners = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ];
+    let sequence = [0, 1, 2, 0, 2, 3];
+    let positions: Vec<[f32; 3]> = sequence.iter().map(|&i| corners[i]).collect();
+    let tex_coords: Vec<[f32; 2]> = positions.iter().map(|p| [p[0], 1.0 - p[1]]).collect();
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&positions);
+    let tex_coords = builder.accessor(&tex_coords);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "TEXCOORD_0": tex_coords },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let (vertices, indices) = reader.to_indexed(0.0);
+    assert_eq!(
+        indices,
+        sequence.iter().map(|&i| i as u32).collect::<Vec<_>>()
+    );
+    let welded: Vec<_> = vertices
+        .iter()
+        .map(|vertex| (vertex.position, vertex.tex_coords_0))
+        .collect();
+    assert_eq!(
+        welded,
+        [
+            ([0.0, 0.0, 0.0], Some([0.0, 1.0])),
+            ([1.0, 0.0, 0.0], Some([1.0, 1.0])),
+            ([1.0, 1.0, 0.0], Some([1.0, 0.0])),
+            ([0.0, 1.0, 0.0], Some([0.0, 0.0])),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_sparse_indices() {
+    let mut builder = common::Builder::new();
+    let base = builder.view(&common::to_bytes(&[[0.0f32; 3]; 3]), None);
+    let in_order = builder.view(&common::to_bytes(&[[1u16], [2]]), None);
+    let out_of_order = builder.view(&common::to_bytes(&[[2u16], [1]]), None);
+    let values = builder.view(&common::to_bytes(&[[1.0f32; 3]; 2]), None);
+    for &indices in &[in_order, out_of_order] {
+        builder.raw_accessor(json!({
+            "bufferView": base,
+            "componentType": 5126,
+            "count": 3,
+            "type": "VEC3",
+            "min": [0.0, 0.0, 0.0],
+            "max": [1.0, 1.0, 1.0],
+            "sparse": {
+                "count": 2,
+                "indices": { "bufferView": indices, "componentType": 5123 },
+                "values": { "bufferView": values },
+            },
+        }));
+    }
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": 0 } },
+        { "attributes": { "POSITION": 1 } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert!(reader.validate_sparse_indices().is_ok());
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    match reader.validate_sparse_indices() {
+        Err(gltf::Error::Validation(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0.as_str(), "accessors[1].sparse.indices");
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_vertices_exceeding_influences() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let joints = builder.accessor(&[[0u8, 1, 2, 3]; 3]);
+    let weights_0 = builder.accessor(&[
+        [0.25f32, 0.25, 0.25, 0.25],
+        [0.5, 0.5, 0.0, 0.0],
+        [0.125, 0.125, 0.125, 0.125],
+    ]);
+    let weights_1 = builder.accessor(&[
+        [0.0f32, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [0.125, 0.125, 0.125, 0.125],
+    ]);
+    let document = builder.mesh(json!([{
+        "attributes": {
+            "POSITION": positions,
+            "JOINTS_0": joints,
+            "JOINTS_1": joints,
+            "WEIGHTS_0": weights_0,
+            "WEIGHTS_1": weights_1,
+        },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(reader.vertices_exceeding_influences(4), [2]);
+    assert!(reader.vertices_exceeding_influences(8).is_empty());
+}
+
+#[test]
+fn test_read_colors_rgb_source() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+    let colors = builder.accessor(&[[1.0f32, 0.5, 0.0], [0.0, 0.25, 1.0]]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "COLOR_0": colors },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let rgb: Vec<[f32; 3]> = reader.read_colors_rgb(0).unwrap().collect();
+    assert_eq!(rgb, vec![[1.0, 0.5, 0.0], [0.0, 0.25, 1.0]]);
+    let rgba: Vec<[f32; 4]> = reader.read_colors_rgba(0).unwrap().collect();
+    assert_eq!(rgba, vec![[1.0, 0.5, 0.0, 1.0], [0.0, 0.25, 1.0, 1.0]]);
+    assert!(reader.read_colors_rgba(1).is_none());
+}
+
+#[test]
+fn test_read_colors_rgba_with_alpha() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+    let rgb = builder.accessor(&[[255u8, 0, 0], [0, 255, 0]]);
+    let rgba = builder.accessor(&[[1.0f32, 0.5, 0.0, 0.75], [0.0, 0.25, 1.0, 1.0]]);
+    builder.accessors[rgb as usize]["normalized"] = json!(true);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "COLOR_0": rgb, "COLOR_1": rgba },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let expanded: Vec<[f32; 4]> = reader
+        .read_colors_rgba_with_alpha(0, 0.0)
+        .unwrap()
+        .collect();
+    assert_eq!(expanded, [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0]]);
+    let kept: Vec<[f32; 4]> = reader
+        .read_colors_rgba_with_alpha(1, 0.0)
+        .unwrap()
+        .collect();
+    assert_eq!(kept, [[1.0, 0.5, 0.0, 0.75], [0.0, 0.25, 1.0, 1.0]]);
+}
+
+#[test]
+fn test_read_color_sets() {
+    use gltf::mesh::util::colors::ColorBlend;
+
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+    let base = builder.accessor(&[[1.0f32, 0.5, 0.0], [0.25, 0.25, 1.0]]);
+    let paint = builder.accessor(&[[0.5f32, 0.5, 0.5, 1.0], [1.0, 0.0, 0.5, 0.5]]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions, "COLOR_0": base, "COLOR_1": paint } },
+        { "attributes": { "POSITION": positions, "COLOR_0": base } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(
+        reader.read_color_sets().unwrap(),
+        [
+            [[1.0, 0.5, 0.0, 1.0], [0.5, 0.5, 0.5, 1.0]],
+            [[0.25, 0.25, 1.0, 1.0], [1.0, 0.0, 0.5, 0.5]],
+        ]
+    );
+    assert_eq!(
+        reader.blend_color_sets(ColorBlend::Multiply).unwrap(),
+        [[0.5, 0.25, 0.0, 1.0], [0.25, 0.0, 0.5, 0.5]]
+    );
+    assert_eq!(
+        reader.blend_color_sets(ColorBlend::Add).unwrap(),
+        [[1.0, 1.0, 0.5, 1.0], [1.0, 0.25, 1.0, 1.0]]
+    );
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(reader.read_color_sets(), None);
+}
+
+#[test]
+fn test_color_has_alpha() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0]]);
+    let rgb = builder.accessor(&[[1.0f32, 0.5, 0.0]]);
+    let rgba = builder.accessor(&[[255u8, 128, 0, 64]]);
+    builder.accessors[rgba as usize]["normalized"] = json!(true);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "COLOR_0": rgb, "COLOR_1": rgba },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    assert_eq!(reader.color_has_alpha(0), Some(false));
+    assert_eq!(reader.color_has_alpha(1), Some(true));
+    assert_eq!(reader.color_has_alpha(2), None);
+}
+
+#[test]
+fn test_apply_morphs() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let normals = builder.accessor(&[[0.0f32, 0.0, 1.0]; 3]);
+    let position_offsets =
+        builder.accessor(&[[2.0f32, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 2.0]]);
+    let normal_offsets = builder.accessor(&[[0.0f32, 1.0, -1.0]; 3]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "NORMAL": normals },
+        "targets": [{ "POSITION": position_offsets, "NORMAL": normal_offsets }],
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    assert_eq!(
+        reader.apply_morphs(&[0.5]).unwrap(),
+        [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 1.0]]
+    );
+    assert_eq!(
+        reader.apply_normal_morphs(&[0.5]).unwrap(),
+        [[0.0, 0.5, 0.5]; 3]
+    );
+    assert!(reader.apply_morphs(&[0.5, 0.5]).is_none());
+}
+
+#[test]
+fn test_line_segments() {
+    let points = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ];
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&points);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions }, "mode": 3 },
+        { "attributes": { "POSITION": positions }, "mode": 2 },
+        { "attributes": { "POSITION": positions } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let segments: Vec<_> = mesh
+        .primitives()
+        .map(|primitive| primitive.reader(|_| Some(&builder.bin)).line_segments())
+        .collect();
+
+    let strip = [
+        [points[0], points[1]],
+        [points[1], points[2]],
+        [points[2], points[3]],
+    ];
+    assert_eq!(segments[0].as_deref(), Some(&strip[..]));
+    let looped = segments[1].as_ref().unwrap();
+    assert_eq!(looped.len(), 4);
+    assert_eq!(looped[..3], strip);
+    assert_eq!(looped[3], [points[3], points[0]]);
+    assert!(segments[2].is_none());
+}
+
+#[test]
+fn test_first_non_finite_vertex() {
+    use gltf::Semantic;
+
+    let mut builder = common::Builder::new();
+    let finite = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let corrupt = builder.accessor(&[[0.0f32, 0.0, 0.0], [1.0, f32::NAN, 0.0], [0.0, 1.0, 0.0]]);
+    let normals = builder.accessor(&[
+        [0.0f32, 0.0, 1.0],
+        [0.0, 0.0, 1.0],
+        [f32::INFINITY, 0.0, 0.0],
+    ]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": finite } },
+        { "attributes": { "POSITION": corrupt } },
+        { "attributes": { "POSITION": finite, "NORMAL": normals } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let found: Vec<_> = mesh
+        .primitives()
+        .map(|primitive| {
+            let reader = primitive.reader(|_| Some(&builder.bin));
+            (
+                reader.first_non_finite_vertex(false),
+                reader.first_non_finite_vertex(true),
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (None, None),
+            (
+                Some((Semantic::Positions, 1)),
+                Some((Semantic::Positions, 1))
+            ),
+            (None, Some((Semantic::Normals, 2))),
+        ]
+    );
+}
+
+#[test]
+fn test_read_sparse_indices() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ]);
+    let base = builder.view(&common::to_bytes(&[[0u16], [1], [2], [0], [0], [0]]), None);
+    let sparse_indices = builder.view(&common::to_bytes(&[[4u8], [5]]), None);
+    let sparse_values = builder.view(&common::to_bytes(&[[2u16], [3]]), None);
+    let sparse = json!({
+        "count": 2,
+        "indices": { "bufferView": sparse_indices, "componentType": 5121 },
+        "values": { "bufferView": sparse_values },
+    });
+    let with_base = builder.raw_accessor(json!({
+        "bufferView": base,
+        "componentType": 5123,
+        "count": 6,
+        "type": "SCALAR",
+        "sparse": sparse,
+    }));
+    let without_base = builder.raw_accessor(json!({
+        "componentType": 5123,
+        "count": 6,
+        "type": "SCALAR",
+        "sparse": sparse,
+    }));
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions }, "indices": with_base },
+        { "attributes": { "POSITION": positions }, "indices": without_base },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let indices: Vec<Vec<u32>> = mesh
+        .primitives()
+        .map(|primitive| {
+            let reader = primitive.reader(|_| Some(&builder.bin));
+            let iter = reader.read_indices().unwrap().into_u32();
+            assert_eq!(iter.len(), 6);
+            iter.collect()
+        })
+        .collect();
+    assert_eq!(indices, [[0, 1, 2, 0, 2, 3], [0, 0, 0, 0, 2, 3]]);
+}
+
+#[test]
+fn test_split_for_u16() {
+    let positions: Vec<[f32; 3]> = (0..69_999).map(|i| [i as f32, 0.0, 0.0]).collect();
+    let mut builder = common::Builder::new();
+    let index = builder.positions(&positions);
+    let document = builder.mesh(json!([{ "attributes": { "POSITION": index } }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let pieces = reader.split_for_u16().unwrap();
+    assert_eq!(pieces.len(), 2);
+    let mut triangles = 0;
+    let mut resolved = Vec::new();
+    for piece in &pieces {
+        assert!(piece.positions.len() <= 65_535);
+        assert!(piece.indices.iter().all(|&index| index < 65_535));
+        assert_eq!(piece.indices.len() % 3, 0);
+        triangles += piece.indices.len() / 3;
+        resolved.extend(piece.indices.iter().map(|&i| piece.positions[i as usize]));
+    }
+    assert_eq!(triangles, 23_333);
+    assert_eq!(resolved, positions);
+}
+
+#[test]
+fn test_split_for_u16_unrepresentable_attributes() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let tex_coords = builder.accessor(&[[0.0f32, 0.0]; 3]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions, "TEXCOORD_1": tex_coords } },
+        { "attributes": { "POSITION": positions, "TEXCOORD_2": tex_coords } },
+        {
+            "attributes": { "POSITION": positions },
+            "targets": [{ "POSITION": positions }],
+        },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let splits: Vec<bool> = mesh
+        .primitives()
+        .map(|primitive| {
+            let reader = primitive.reader(|_| Some(&builder.bin));
+            reader.split_for_u16().is_some()
+        })
+        .collect();
+    assert_eq!(splits, [true, false, false]);
+}
+
+#[test]
+fn test_with_generated_normals_and_tangents() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ]);
+    let tex_coords = builder.accessor(&[[0.0f32, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]]);
+    let indices = builder.scalars(&[0u16, 1, 2, 0, 2, 3]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "TEXCOORD_0": tex_coords },
+        "indices": indices,
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let soa = reader.with_generated_normals_and_tangents().unwrap();
+    assert_eq!(soa.normals, [[0.0, 0.0, 1.0]; 4]);
+    assert_eq!(soa.tangents.len(), 4);
+    for (tangent, normal) in soa.tangents.iter().zip(&soa.normals) {
+        let length = (tangent[0].powi(2) + tangent[1].powi(2) + tangent[2].powi(2)).sqrt();
+        assert!((length - 1.0).abs() < 1e-6);
+        let projection = tangent[0] * normal[0] + tangent[1] * normal[1] + tangent[2] * normal[2];
+        assert!(projection.abs() < 1e-6);
+        assert!(tangent[3] == 1.0 || tangent[3] == -1.0);
+    }
+    // `u` increases along +X, while `v` increases along -Y.
+    assert_eq!(soa.tangents[0], [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(soa.indices, [0, 1, 2, 0, 2, 3]);
+}
+
+#[test]
+fn test_tangent_geometry() {
+    use gltf::mesh::util::generate::TangentGeometry;
+    use gltf::mesh::util::soa::SoaMesh;
+
+    let mut mesh = SoaMesh {
+        positions: vec![
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ],
+        normals: vec![[0.0, 0.0, 1.0]; 4],
+        tex_coords_0: vec![[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]],
+        indices: vec![0, 1, 2, 0, 2, 3],
+        ..SoaMesh::default()
+    };
+    {
+        let mut geometry = TangentGeometry::new(&mut mesh).unwrap();
+        assert_eq!(geometry.num_faces(), 2);
+        assert_eq!(geometry.num_vertices_of_face(1), 3);
+        assert_eq!(geometry.position(1, 2), [0.0, 1.0, 0.0]);
+        assert_eq!(geometry.normal(1, 2), [0.0, 0.0, 1.0]);
+        assert_eq!(geometry.tex_coord(1, 2), [0.0, 0.0]);
+        geometry.generate_tangents();
+    }
+    assert_eq!(mesh.tangents, [[1.0, 0.0, 0.0, 1.0]; 4]);
+
+    mesh.tex_coords_0.clear();
+    assert!(TangentGeometry::new(&mut mesh).is_none());
+}
+
+#[cfg(feature = "mikktspace")]
+#[test]
+fn test_mikktspace_tangents() {
+    use gltf::mesh::util::generate::TangentGeometry;
+    use gltf::mesh::util::soa::SoaMesh;
+
+    let mut mesh = SoaMesh {
+        positions: vec![
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ],
+        normals: vec![[0.0, 0.0, 1.0]; 4],
+        // Mirrored along the U axis, so the tangents point along -X.
+        tex_coords_0: vec![[1.0, 1.0], [0.0, 1.0], [0.0, 0.0], [1.0, 0.0]],
+        indices: vec![0, 1, 2, 0, 2, 3],
+        ..SoaMesh::default()
+    };
+    let mut geometry = TangentGeometry::new(&mut mesh).unwrap();
+    assert!(mikktspace::generate_tangents(&mut geometry));
+    for tangent in &mesh.tangents {
+        approx::assert_relative_eq!(&tangent[..], &[-1.0, 0.0, 0.0, 1.0][..], epsilon = 1e-6);
+    }
+}
+
+#[test]
+fn test_read_indices_with_offsets_into_shared_view() {
+    let positions = [[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    let mut builder = common::Builder::new();
+    builder.view(&[0xff; 8], None);
+    let mut shared = common::to_bytes(&positions);
+    shared.extend(common::to_bytes(&[[2u16], [1], [0], [0xffff]]));
+    let view = builder.view(&shared, None);
+    let position_index = builder.raw_accessor(json!({
+        "bufferView": view,
+        "componentType": 5126,
+        "count": 3,
+        "type": "VEC3",
+        "min": [0.0, 0.0, 0.0],
+        "max": [1.0, 1.0, 0.0],
+    }));
+    let index_index = builder.raw_accessor(json!({
+        "bufferView": view,
+        "byteOffset": 36,
+        "componentType": 5123,
+        "count": 3,
+        "type": "SCALAR",
+    }));
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": position_index },
+        "indices": index_index,
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let indices: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+    assert_eq!(indices, [2, 1, 0]);
+    let positions_read: Vec<[f32; 3]> = reader.read_positions().unwrap().collect();
+    assert_eq!(positions_read, positions);
+}
+
+#[test]
+fn test_build_bvh_ray_intersect() {
+    // Two 10x10 grids of quads at z = 0 and z = -1, each quad split into
+    // the triangles [00, 10, 11] and [00, 11, 01].
+    let n = 10u32;
+    let mut positions = Vec::new();
+    for &z in &[0.0, -1.0] {
+        for y in 0..=n {
+            for x in 0..=n {
+                positions.push([x as f32, y as f32, z]);
+            }
+        }
+    }
+    let mut indices = Vec::new();
+    for layer in 0..2 {
+        let base = layer * (n + 1) * (n + 1);
+        for y in 0..n {
+            for x in 0..n {
+                let v00 = base + y * (n + 1) + x;
+                let (v10, v01, v11) = (v00 + 1, v00 + n + 1, v00 + n + 2);
+                indices.extend_from_slice(&[v00, v10, v11, v00, v11, v01]);
+            }
+        }
+    }
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&positions);
+    let indices = builder.scalars(&indices);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions },
+        "indices": indices,
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let bvh = reader.build_bvh().unwrap();
+
+    let bounds = bvh.bounds().unwrap();
+    assert_eq!(bounds.min, [0.0, 0.0, -1.0]);
+    assert_eq!(bounds.max, [10.0, 10.0, 0.0]);
+
+    let hit = bvh
+        .ray_intersect([3.75, 4.25, 5.0], [0.0, 0.0, -1.0])
+        .unwrap();
+    assert_eq!(hit.triangle, 2 * (4 * n + 3) as usize);
+    assert_eq!(hit.t, 5.0);
+    assert_eq!(hit.barycentric, [0.25, 0.5, 0.25]);
+
+    // From below, the lower grid is hit first.
+    let hit = bvh
+        .ray_intersect([3.75, 4.25, -3.0], [0.0, 0.0, 2.0])
+        .unwrap();
+    assert_eq!(hit.triangle, 2 * (n * n + 4 * n + 3) as usize);
+    assert_eq!(hit.t, 1.0);
+
+    assert_eq!(bvh.ray_intersect([3.75, 4.25, 5.0], [0.0, 0.0, 1.0]), None);
+    assert_eq!(bvh.ray_intersect([12.0, 4.25, 5.0], [0.0, 0.0, -1.0]), None);
+}
+
+#[test]
+fn test_face_normals() {
+    // A quad folded along its diagonal, as a triangle strip.
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [1.0, 1.0, 1.0],
+    ]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions }, "mode": 5 },
+        { "attributes": { "POSITION": positions }, "mode": 1 },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let normals = reader.face_normals().unwrap();
+    assert_eq!(normals.len(), 2);
+    assert_eq!(normals[0], [0.0, 0.0, 1.0]);
+    let s = 1.0 / 3.0f32.sqrt();
+    for (actual, expected) in normals[1].iter().zip(&[-s, -s, s]) {
+        assert!((actual - expected).abs() < 1e-6);
+    }
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(reader.face_normals(), None);
+}
+
+#[test]
+fn test_indices_u16_slice() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 4]);
+    let short = builder.scalars(&[0u16, 1, 2, 2, 1, 3]);
+    let int = builder.scalars(&[0u32, 1, 2, 2, 1, 3]);
+    let document = builder.mesh(json!([
+        { "attributes": { "POSITION": positions }, "indices": short },
+        { "attributes": { "POSITION": positions }, "indices": int },
+        { "attributes": { "POSITION": positions } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let slice = reader.indices_u16_slice().unwrap();
+    let widened: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+    let slice: Vec<u32> = slice.iter().map(|&index| u32::from(index)).collect();
+    assert_eq!(slice, widened);
+
+    for primitive in primitives {
+        let reader = primitive.reader(|_| Some(&builder.bin));
+        assert!(reader.indices_u16_slice().is_none());
+    }
+}
+
+#[test]
+fn test_vertices_indexed() {
+    let positions = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0],
+    ];
+    let tex_coords = [[0.0f32, 1.0], [1.0, 1.0], [0.0, 0.0], [1.0, 0.0]];
+    let indices = [2u16, 0, 1, 2, 1, 3];
+
+    let mut builder = common::Builder::new();
+    let position_index = builder.positions(&positions);
+    let tex_coord_index = builder.accessor(&tex_coords);
+    let index_index = builder.scalars(&indices);
+    let document = builder.mesh(json!([
+        {
+            "attributes": { "POSITION": position_index, "TEXCOORD_0": tex_coord_index },
+            "indices": index_index,
+        },
+        { "attributes": { "POSITION": position_index } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let indexed: Vec<_> = reader
+        .vertices_indexed()
+        .map(|vertex| (vertex.position, vertex.tex_coords_0))
+        .collect();
+    let expected: Vec<_> = indices
+        .iter()
+        .map(|&i| (positions[i as usize], Some(tex_coords[i as usize])))
+        .collect();
+    assert_eq!(indexed, expected);
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let positions_in_order: Vec<_> = reader.vertices_indexed().map(|v| v.position).collect();
+    assert_eq!(positions_in_order, positions);
+}
+
+#[test]
+fn test_read_vec3_tangents() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 2]);
+    let tangents = builder.accessor(&[[1.0f32, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": positions, "TANGENT": tangents },
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let read = reader.read_tangents().map(Iterator::collect::<Vec<_>>);
+    if cfg!(feature = "lenient") {
+        assert_eq!(read.unwrap(), [[1.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 1.0]]);
+    } else {
+        assert!(read.is_none());
+    }
+}
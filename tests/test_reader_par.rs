@@ -0,0 +1,103 @@
+#![cfg(feature = "rayon")]
+
+mod common;
+
+use serde_json::json;
+
+fn bits(data: &[[f32; 3]]) -> Vec<[u32; 3]> {
+    data.iter()
+        .map(|v| [v[0].to_bits(), v[1].to_bits(), v[2].to_bits()])
+        .collect()
+}
+
+#[test]
+fn test_read_par_matches_serial() {
+    let count = 10_000;
+    let mut interleaved = Vec::with_capacity(count * 2);
+    for i in 0..count {
+        let x = i as f32;
+        interleaved.push([x * 0.5, -x, f32::MIN_POSITIVE * x]);
+        interleaved.push([x.sin(), x.cos(), 1.0 / (x + 1.0)]);
+    }
+    let tangents: Vec<[f32; 4]> = (0..count).map(|i| [i as f32, 0.0, 1.0, -1.0]).collect();
+    let tex_coords: Vec<[u16; 2]> = (0..count).map(|i| [i as u16, u16::MAX]).collect();
+    let indices: Vec<u32> = (0..count as u32).rev().collect();
+
+    let mut builder = common::Builder::new();
+    let view = builder.view(&common::to_bytes(&interleaved), Some(24));
+    builder.raw_accessor(json!({
+        "bufferView": view,
+        "componentType": 5126,
+        "count": count,
+        "type": "VEC3",
+    }));
+    builder.raw_accessor(json!({
+        "bufferView": view,
+        "byteOffset": 12,
+        "componentType": 5126,
+        "count": count,
+        "type": "VEC3",
+    }));
+    let tangents = builder.accessor(&tangents);
+    let tex_coords = builder.accessor(&tex_coords);
+    builder.accessors[tex_coords as usize]["normalized"] = json!(true);
+    let indices = builder.scalars(&indices);
+    let sparse_indices = builder.view(&common::to_bytes(&[[3u16], [9000]]), None);
+    let sparse_values = builder.view(&common::to_bytes(&[[7.0f32; 3]; 2]), None);
+    builder.raw_accessor(json!({
+        "bufferView": view,
+        "componentType": 5126,
+        "count": count,
+        "type": "VEC3",
+        "sparse": {
+            "count": 2,
+            "indices": { "bufferView": sparse_indices, "componentType": 5123 },
+            "values": { "bufferView": sparse_values },
+        },
+    }));
+    let document = builder.mesh(json!([
+        {
+            "attributes": {
+                "POSITION": 0,
+                "NORMAL": 1,
+                "TANGENT": tangents,
+                "TEXCOORD_0": tex_coords,
+            },
+            "indices": indices,
+        },
+        { "attributes": { "POSITION": 5 } },
+    ]));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let serial: Vec<[f32; 3]> = reader.read_positions().unwrap().collect();
+    let parallel = reader.read_positions_par().unwrap();
+    assert_eq!(bits(&parallel), bits(&serial));
+    assert_eq!(reader.to_soa_par(), reader.to_soa());
+
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    let serial: Vec<[f32; 3]> = reader.read_positions().unwrap().collect();
+    let parallel = reader.read_positions_par().unwrap();
+    assert_eq!(parallel[9000], [7.0; 3]);
+    assert_eq!(bits(&parallel), bits(&serial));
+}
+
+#[test]
+fn test_read_par_empty_accessor() {
+    let mut builder = common::Builder::new();
+    let view = builder.view(&[0; 12], None);
+    let positions = builder.raw_accessor(json!({
+        "bufferView": view,
+        "componentType": 5126,
+        "count": 0,
+        "type": "VEC3",
+    }));
+    let document = builder.mesh(json!([{ "attributes": { "POSITION": positions } }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+    assert_eq!(reader.read_positions_par(), Some(Vec::new()));
+}
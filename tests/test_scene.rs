@@ -0,0 +1,53 @@
+mod common;
+
+use serde_json::json;
+
+#[test]
+fn test_mesh_instances_compose_transforms() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let document = builder.document(json!({
+        "meshes": [{ "primitives": [{ "attributes": { "POSITION": positions } }] }],
+        "nodes": [
+            { "translation": [1.0, 0.0, 0.0], "children": [1] },
+            { "scale": [2.0, 2.0, 2.0], "mesh": 0 },
+            {
+                "matrix": [
+                    1.0, 0.0, 0.0, 0.0,
+                    0.0, 1.0, 0.0, 0.0,
+                    0.0, 0.0, 1.0, 0.0,
+                    0.0, 3.0, 0.0, 1.0,
+                ],
+                "mesh": 0,
+            },
+        ],
+        "scenes": [{ "nodes": [0, 2] }],
+    }));
+    let instances: Vec<_> = document
+        .mesh_instances()
+        .map(|(mesh, transform)| (mesh.index(), transform))
+        .collect();
+    assert_eq!(
+        instances,
+        [
+            (
+                0,
+                [
+                    [2.0, 0.0, 0.0, 0.0],
+                    [0.0, 2.0, 0.0, 0.0],
+                    [0.0, 0.0, 2.0, 0.0],
+                    [1.0, 0.0, 0.0, 1.0],
+                ]
+            ),
+            (
+                0,
+                [
+                    [1.0, 0.0, 0.0, 0.0],
+                    [0.0, 1.0, 0.0, 0.0],
+                    [0.0, 0.0, 1.0, 0.0],
+                    [0.0, 3.0, 0.0, 1.0],
+                ]
+            ),
+        ]
+    );
+}
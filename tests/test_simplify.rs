@@ -0,0 +1,109 @@
+mod common;
+
+use gltf::mesh::util::simplify::simplify;
+use serde_json::json;
+
+/// Returns a closed UV sphere of unit radius with outward facing triangles.
+fn sphere(rings: u32, segments: u32) -> (Vec<[f32; 3]>, Vec<u32>) {
+    let mut positions = vec![[0.0, 1.0, 0.0]];
+    for ring in 1..rings {
+        let theta = std::f32::consts::PI * ring as f32 / rings as f32;
+        for segment in 0..segments {
+            let phi = 2.0 * std::f32::consts::PI * segment as f32 / segments as f32;
+            positions.push([
+                theta.sin() * phi.cos(),
+                theta.cos(),
+                -theta.sin() * phi.sin(),
+            ]);
+        }
+    }
+    positions.push([0.0, -1.0, 0.0]);
+
+    let bottom = positions.len() as u32 - 1;
+    let vertex = |ring: u32, segment: u32| 1 + (ring - 1) * segments + segment % segments;
+    let mut indices = Vec::new();
+    for segment in 0..segments {
+        indices.extend_from_slice(&[0, vertex(1, segment), vertex(1, segment + 1)]);
+        let last = rings - 1;
+        indices.extend_from_slice(&[bottom, vertex(last, segment + 1), vertex(last, segment)]);
+    }
+    for ring in 1..rings - 1 {
+        for segment in 0..segments {
+            let (a, b) = (vertex(ring, segment), vertex(ring, segment + 1));
+            let (c, d) = (vertex(ring + 1, segment), vertex(ring + 1, segment + 1));
+            indices.extend_from_slice(&[a, c, d, a, d, b]);
+        }
+    }
+    (positions, indices)
+}
+
+fn bounds(positions: impl Iterator<Item = [f32; 3]>) -> ([f32; 3], [f32; 3]) {
+    let mut min = [f32::MAX; 3];
+    let mut max = [f32::MIN; 3];
+    for p in positions {
+        for ((min, max), p) in min.iter_mut().zip(max.iter_mut()).zip(p.iter()) {
+            *min = min.min(*p);
+            *max = max.max(*p);
+        }
+    }
+    (min, max)
+}
+
+#[test]
+fn test_simplify_sphere() {
+    let (positions, indices) = sphere(32, 64);
+    let triangle_count = indices.len() / 3;
+
+    let simplified = simplify(&positions, &indices, 0.25);
+    assert_eq!(simplified.len() % 3, 0);
+    let simplified_count = simplified.len() / 3;
+    assert!(simplified_count < triangle_count / 3);
+    assert!(simplified_count > triangle_count / 8);
+
+    // The remaining vertices stay on the sphere, whose extent barely changes.
+    let (min, max) = bounds(simplified.iter().map(|&i| positions[i as usize]));
+    for axis in 0..3 {
+        assert!(min[axis] < -0.95 && max[axis] > 0.95);
+    }
+
+    assert_eq!(simplify(&positions, &indices, 1.0), indices);
+    assert!(simplify(&[], &[], 0.5).is_empty());
+}
+
+#[test]
+fn test_simplify_drops_degenerate_triangles() {
+    let (positions, indices) = sphere(8, 16);
+    let triangle_count = indices.len() / 3;
+    let [c, d] = [indices[1], indices[2]];
+    let mut stitched = indices.clone();
+    stitched.extend_from_slice(&[c, c, d]);
+
+    // Only the degenerate triangle goes, rather than every triangle around
+    // its repeated corner.
+    let ratio = triangle_count as f32 / (triangle_count + 1) as f32;
+    assert_eq!(simplify(&positions, &stitched, ratio), indices);
+}
+
+#[test]
+fn test_reader_simplify() {
+    let (positions, indices) = sphere(16, 32);
+    let mut builder = common::Builder::new();
+    let position_index = builder.positions(&positions);
+    let index_index = builder.scalars(&indices);
+    let document = builder.mesh(json!([{
+        "attributes": { "POSITION": position_index },
+        "indices": index_index,
+    }]));
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&builder.bin));
+
+    let (vertices, simplified) = reader.simplify(0.5);
+    assert!(simplified.len() < indices.len() * 3 / 4);
+    assert!(vertices.len() < positions.len());
+    assert!(simplified.iter().all(|&i| (i as usize) < vertices.len()));
+    let (min, max) = bounds(vertices.iter().map(|vertex| vertex.position));
+    for axis in 0..3 {
+        assert!(min[axis] < -0.95 && max[axis] > 0.95);
+    }
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "KHR_materials_variants")]
+
+mod common;
+
+use serde_json::json;
+
+#[test]
+fn test_variant_names() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let document = builder.document(json!({
+        "extensionsUsed": ["KHR_materials_variants"],
+        "extensions": {
+            "KHR_materials_variants": {
+                "variants": [{ "name": "red" }, { "name": "green" }, { "name": "blue" }],
+            },
+        },
+        "materials": [{}, {}],
+        "meshes": [{
+            "primitives": [{
+                "attributes": { "POSITION": positions },
+                "extensions": {
+                    "KHR_materials_variants": {
+                        "mappings": [
+                            { "material": 0, "variants": [0, 2] },
+                            { "material": 1, "variants": [1] },
+                        ],
+                    },
+                },
+            }],
+        }],
+    }));
+    let names = document.variant_names();
+    assert_eq!(names, ["red", "green", "blue"]);
+
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let mapped: Vec<(usize, Vec<&str>)> = primitive
+        .mappings()
+        .map(|mapping| {
+            let variants = mapping
+                .variants()
+                .iter()
+                .map(|&index| names[index as usize])
+                .collect();
+            (mapping.material().index().unwrap(), variants)
+        })
+        .collect();
+    assert_eq!(mapped, [(0, vec!["red", "blue"]), (1, vec!["green"])]);
+
+    let empty = builder.document(json!({}));
+    assert!(empty.variant_names().is_empty());
+}
+
+#[test]
+fn test_material_for_variant_falls_back_to_base_material() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let mappings = json!({
+        "KHR_materials_variants": {
+            "mappings": [{ "material": 1, "variants": [0] }],
+        },
+    });
+    let document = builder.document(json!({
+        "extensionsUsed": ["KHR_materials_variants"],
+        "extensions": {
+            "KHR_materials_variants": {
+                "variants": [{ "name": "red" }, { "name": "green" }],
+            },
+        },
+        "materials": [{}, {}],
+        "meshes": [{
+            "primitives": [
+                { "attributes": { "POSITION": positions }, "material": 0, "extensions": mappings },
+                { "attributes": { "POSITION": positions }, "extensions": mappings },
+            ],
+        }],
+    }));
+    let mesh = document.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    assert_eq!(primitive.material_for_variant(0).index(), Some(1));
+    assert_eq!(primitive.material_for_variant(1).index(), Some(0));
+
+    let primitive = primitives.next().unwrap();
+    assert_eq!(primitive.material_for_variant(0).index(), Some(1));
+    assert_eq!(primitive.material_for_variant(1).index(), None);
+}
+
+#[test]
+fn test_variant_mappings() {
+    let mut builder = common::Builder::new();
+    let positions = builder.positions(&[[0.0; 3]; 3]);
+    let root = builder.root(json!({
+        "extensionsUsed": ["KHR_materials_variants"],
+        "extensions": {
+            "KHR_materials_variants": {
+                "variants": [{ "name": "red" }, { "name": "green" }],
+            },
+        },
+        "materials": [{}, {}, {}],
+        "meshes": [{
+            "primitives": [
+                {
+                    "attributes": { "POSITION": positions },
+                    "extensions": {
+                        "KHR_materials_variants": {
+                            "mappings": [
+                                { "material": 2, "variants": [0] },
+                                { "material": 1, "variants": [1, 0] },
+                            ],
+                        },
+                    },
+                },
+                { "attributes": { "POSITION": positions } },
+            ],
+        }],
+    }));
+    let primitives = &root.meshes[0].primitives;
+
+    let mappings: Vec<(usize, &[u32])> = primitives[0]
+        .variant_mappings()
+        .map(|(material, variants)| (material.value(), variants))
+        .collect();
+    assert_eq!(mappings, [(2, &[0][..]), (1, &[1, 0][..])]);
+    assert_eq!(primitives[1].variant_mappings().count(), 0);
+}